@@ -0,0 +1,190 @@
+use std::io;
+use std::io::{Cursor, Read, Write};
+use std::time::{Duration, Instant};
+use rw_utils::from_read::{DefaultFromRead, FromRead, FromReader};
+use rw_utils::num_read::NumRead;
+use rw_utils::num_write::NumWrite;
+use rw_utils::to_write::{ToWrite, ToWriter};
+
+const RECORDS: usize = 1_000_000;
+const ROUNDS: usize = 10;
+
+///
+/// Small record read and written through the dyn traits.
+///
+#[derive(Debug, Default, Clone, Copy)]
+struct DynRecord {
+    id: u32,
+    kind: u8,
+    flags: u16,
+    value: i64,
+}
+
+impl FromRead for DynRecord {
+    fn copy_from_read(&mut self, mut reader: &mut dyn Read) -> io::Result<()> {
+        self.id = reader.read_u32_le()?;
+        self.kind = reader.read_u8()?;
+        self.flags = reader.read_u16_le()?;
+        self.value = reader.read_i64_le()?;
+        return Ok(());
+    }
+}
+
+impl ToWrite for DynRecord {
+    fn copy_to_write(&self, mut writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_u32_le(self.id)?;
+        writer.write_u8(self.kind)?;
+        writer.write_u16_le(self.flags)?;
+        writer.write_i64_le(self.value)?;
+        return Ok(());
+    }
+}
+
+///
+/// The same record read and written through the generic traits.
+///
+#[derive(Debug, Default, Clone, Copy)]
+struct GenericRecord {
+    id: u32,
+    kind: u8,
+    flags: u16,
+    value: i64,
+}
+
+impl FromReader for GenericRecord {
+    fn copy_from_reader<R: Read + ?Sized>(&mut self, mut reader: &mut R) -> io::Result<()> {
+        self.id = reader.read_u32_le()?;
+        self.kind = reader.read_u8()?;
+        self.flags = reader.read_u16_le()?;
+        self.value = reader.read_i64_le()?;
+        return Ok(());
+    }
+}
+
+impl ToWriter for GenericRecord {
+    fn copy_to_writer<W: Write + ?Sized>(&self, mut writer: &mut W) -> io::Result<()> {
+        writer.write_u32_le(self.id)?;
+        writer.write_u8(self.kind)?;
+        writer.write_u16_le(self.flags)?;
+        writer.write_i64_le(self.value)?;
+        return Ok(());
+    }
+}
+
+fn checksum(id: u32, kind: u8, flags: u16, value: i64) -> u64 {
+    return (id as u64) ^ (kind as u64) ^ (flags as u64) ^ (value as u64);
+}
+
+///
+/// Runs the closure ROUNDS times and returns the fastest run and the result of the last run.
+///
+fn measure<F: FnMut() -> io::Result<u64>>(mut run: F) -> io::Result<(Duration, u64)> {
+    let mut best = Duration::MAX;
+    let mut result = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        result = run()?;
+        best = best.min(start.elapsed());
+    }
+
+    return Ok((best, result));
+}
+
+fn report(name: &str, dyn_time: Duration, generic_time: Duration) {
+    println!("{:<6} dyn {:>10.3} ms  generic {:>10.3} ms  speedup {:.2}x",
+             name,
+             dyn_time.as_secs_f64() * 1000.0,
+             generic_time.as_secs_f64() * 1000.0,
+             dyn_time.as_secs_f64() / generic_time.as_secs_f64());
+}
+
+fn main() -> io::Result<()> {
+    let dyn_records: Vec<DynRecord> = (0..RECORDS)
+        .map(|i| DynRecord { id: i as u32, kind: i as u8, flags: (i * 7) as u16, value: (i * 31) as i64 })
+        .collect();
+    let generic_records: Vec<GenericRecord> = dyn_records.iter()
+        .map(|r| GenericRecord { id: r.id, kind: r.kind, flags: r.flags, value: r.value })
+        .collect();
+
+    //The buffer is reused so that page faults of a fresh allocation are not measured.
+    let mut buffer: Vec<u8> = Vec::with_capacity(RECORDS * 15);
+    let (dyn_write, dyn_len) = measure(|| {
+        buffer.clear();
+        for record in dyn_records.iter() {
+            record.copy_to_write(&mut buffer)?;
+        }
+        return Ok(buffer.len() as u64);
+    })?;
+
+    let (generic_write, generic_len) = measure(|| {
+        buffer.clear();
+        for record in generic_records.iter() {
+            record.copy_to_writer(&mut buffer)?;
+        }
+        return Ok(buffer.len() as u64);
+    })?;
+
+    assert_eq!(dyn_len, generic_len);
+    report("write", dyn_write, generic_write);
+
+    let mut data: Vec<u8> = Vec::with_capacity(RECORDS * 15);
+    for record in generic_records.iter() {
+        record.copy_to_writer(&mut data)?;
+    }
+
+    let (dyn_read, dyn_sum) = measure(|| {
+        //The reader is chosen at runtime like it usually is when reading through dyn Read,
+        //otherwise the compiler may see through the vtable.
+        let mut cursor = Cursor::new(data.as_slice());
+        let mut empty = io::empty();
+        let reader: &mut dyn Read = if data.is_empty() { &mut empty } else { &mut cursor };
+        let mut record = DynRecord::default();
+        let mut sum = 0u64;
+        for _ in 0..RECORDS {
+            record.copy_from_read(reader)?;
+            sum = sum.wrapping_add(checksum(record.id, record.kind, record.flags, record.value));
+        }
+        return Ok(sum);
+    })?;
+
+    let (generic_read, generic_sum) = measure(|| {
+        let mut cursor = Cursor::new(data.as_slice());
+        let mut record = GenericRecord::default();
+        let mut sum = 0u64;
+        for _ in 0..RECORDS {
+            record.copy_from_reader(&mut cursor)?;
+            sum = sum.wrapping_add(checksum(record.id, record.kind, record.flags, record.value));
+        }
+        return Ok(sum);
+    })?;
+
+    assert_eq!(dyn_sum, generic_sum);
+    report("read", dyn_read, generic_read);
+
+    //The constructors of ReadFrom use the generic method if there is one.
+    let (dyn_slice, dyn_sum) = measure(|| {
+        let mut remainder = data.as_slice();
+        let mut sum = 0u64;
+        while !remainder.is_empty() {
+            let (record, rest) = DynRecord::from_slice_partial(remainder)?;
+            remainder = rest;
+            sum = sum.wrapping_add(checksum(record.id, record.kind, record.flags, record.value));
+        }
+        return Ok(sum);
+    })?;
+
+    let (generic_slice, generic_sum) = measure(|| {
+        let mut remainder = data.as_slice();
+        let mut sum = 0u64;
+        while !remainder.is_empty() {
+            let (record, rest) = GenericRecord::from_slice_partial(remainder)?;
+            remainder = rest;
+            sum = sum.wrapping_add(checksum(record.id, record.kind, record.flags, record.value));
+        }
+        return Ok(sum);
+    })?;
+
+    assert_eq!(dyn_sum, generic_sum);
+    report("slice", dyn_slice, generic_slice);
+    return Ok(());
+}
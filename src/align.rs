@@ -0,0 +1,115 @@
+use std::io;
+use std::io::{Error, ErrorKind, Read, Write};
+use crate::counting::Position;
+
+///
+/// Returns the amount of bytes needed to advance the position to the next multiple of alignment.
+///
+fn padding_length(position: u64, alignment: u64) -> io::Result<u64> {
+    if alignment == 0 {
+        return Err(Error::new(ErrorKind::InvalidInput, "alignment is 0"));
+    }
+
+    return Ok((alignment - position % alignment) % alignment);
+}
+
+///
+/// Trait that provides methods to skip padding up to an alignment boundary.
+/// Automatically implemented for all implementations of io::Read that know their position,
+/// wrap the reader in a CountingReader if it does not.
+/// The alignment is relative to position 0 and does not have to be a power of 2.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait AlignRead : private::ReadSealed {
+
+    ///
+    /// Discards bytes until the position is a multiple of alignment.
+    /// Returns the amount of bytes skipped.
+    ///
+    fn skip_to_alignment(&mut self, alignment: u64) -> io::Result<u64>;
+
+    ///
+    /// Reads bytes until the position is a multiple of alignment and fails with an InvalidPadding error of the io kind InvalidData if any of them is not the given padding byte.
+    /// The offset of the error is the position of the invalid byte.
+    /// Returns the amount of bytes read.
+    ///
+    fn expect_padding(&mut self, alignment: u64, padding: u8) -> io::Result<u64>;
+}
+
+///
+/// Trait that provides methods to write padding up to an alignment boundary.
+/// Automatically implemented for all implementations of io::Write that know their position,
+/// wrap the writer in a CountingWriter if it does not.
+/// The alignment is relative to position 0 and does not have to be a power of 2.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait AlignWrite : private::WriteSealed {
+
+    ///
+    /// Writes the fill byte until the position is a multiple of alignment.
+    /// Returns the amount of bytes written.
+    ///
+    fn write_padding_to_alignment(&mut self, alignment: u64, fill: u8) -> io::Result<u64>;
+}
+
+impl <T> AlignRead for T where T: Read + Position {
+    fn skip_to_alignment(&mut self, alignment: u64) -> io::Result<u64> {
+        let length = padding_length(self.position(), alignment)?;
+        let skipped = io::copy(&mut self.take(length), &mut io::sink())?;
+        if skipped != length {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        return Ok(length);
+    }
+
+    fn expect_padding(&mut self, alignment: u64, padding: u8) -> io::Result<u64> {
+        let length = padding_length(self.position(), alignment)?;
+        let mut buf = [0u8; 64];
+        let mut remaining = length;
+        while remaining > 0 {
+            let chunk = &mut buf[..remaining.min(64) as usize];
+            let start = self.position();
+            self.read_exact(chunk)?;
+            if let Some(index) = chunk.iter().position(|b| *b != padding) {
+                return Err(crate::error::Error::new(crate::error::ErrorKind::InvalidPadding, "invalid padding byte")
+                    .with_offset(start + index as u64)
+                    .into());
+            }
+
+            remaining -= chunk.len() as u64;
+        }
+
+        return Ok(length);
+    }
+}
+
+impl <T> AlignWrite for T where T: Write + Position {
+    fn write_padding_to_alignment(&mut self, alignment: u64, fill: u8) -> io::Result<u64> {
+        let length = padding_length(self.position(), alignment)?;
+        let buf = [fill; 64];
+        let mut remaining = length;
+        while remaining > 0 {
+            let chunk = &buf[..remaining.min(64) as usize];
+            self.write_all(chunk)?;
+            remaining -= chunk.len() as u64;
+        }
+
+        return Ok(length);
+    }
+}
+
+mod private {
+    use std::io::{Read, Write};
+    use crate::counting::Position;
+
+    impl <T> ReadSealed for T where T: Read + Position {}
+    pub trait ReadSealed {
+
+    }
+
+    impl <T> WriteSealed for T where T: Write + Position {}
+    pub trait WriteSealed {
+
+    }
+}
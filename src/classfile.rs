@@ -0,0 +1,438 @@
+use std::io;
+use std::io::{Cursor, Error, ErrorKind, Read, Write};
+use crate::error::ResultExt;
+use crate::from_read::FromRead;
+use crate::num_read::NumRead;
+use crate::num_write::NumWrite;
+use crate::string_read::StringRead;
+use crate::counting::{CountingReader, Position};
+use crate::error::check_length;
+use crate::to_write::ToWrite;
+
+pub const CLASS_MAGIC: u32 = 0xCAFEBABE;
+
+pub const CONSTANT_UTF8: u8 = 1;
+pub const CONSTANT_INTEGER: u8 = 3;
+pub const CONSTANT_FLOAT: u8 = 4;
+pub const CONSTANT_LONG: u8 = 5;
+pub const CONSTANT_DOUBLE: u8 = 6;
+pub const CONSTANT_CLASS: u8 = 7;
+pub const CONSTANT_STRING: u8 = 8;
+pub const CONSTANT_FIELDREF: u8 = 9;
+pub const CONSTANT_METHODREF: u8 = 10;
+pub const CONSTANT_INTERFACE_METHODREF: u8 = 11;
+pub const CONSTANT_NAME_AND_TYPE: u8 = 12;
+pub const CONSTANT_METHOD_HANDLE: u8 = 15;
+pub const CONSTANT_METHOD_TYPE: u8 = 16;
+pub const CONSTANT_DYNAMIC: u8 = 17;
+pub const CONSTANT_INVOKE_DYNAMIC: u8 = 18;
+pub const CONSTANT_MODULE: u8 = 19;
+pub const CONSTANT_PACKAGE: u8 = 20;
+
+///
+/// A jvm class file as described in chapter 4 of the java virtual machine specification.
+///
+/// Attributes are not interpreted and kept as raw bytes.
+/// Writing a ClassFile that was read produces the exact same bytes it was read from.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClassFile {
+    pub minor_version: u16,
+    pub major_version: u16,
+
+    ///
+    /// The constant pool indexed by the constant pool index used in the class file.
+    /// Index 0 and the index following a Long or Double constant hold Constant::Unusable.
+    /// An empty constant pool is written as if it only contained index 0.
+    ///
+    pub constant_pool: Vec<Constant>,
+    pub access_flags: u16,
+    pub this_class: u16,
+
+    ///
+    /// 0 if the class has no super class which is only the case for java/lang/Object and module-info.
+    ///
+    pub super_class: u16,
+    pub interfaces: Vec<u16>,
+    pub fields: Vec<MemberInfo>,
+    pub methods: Vec<MemberInfo>,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    ///
+    /// Placeholder for the constant pool indices that do not hold a constant.
+    ///
+    Unusable,
+
+    ///
+    /// Java modified utf-8 encoded bytes. Kept as is because they are not guaranteed to be valid.
+    ///
+    Utf8(Vec<u8>),
+    Integer(i32),
+    Float(f32),
+    Long(i64),
+    Double(f64),
+    Class(u16),
+    String(u16),
+    FieldRef { class_index: u16, name_and_type_index: u16 },
+    MethodRef { class_index: u16, name_and_type_index: u16 },
+    InterfaceMethodRef { class_index: u16, name_and_type_index: u16 },
+    NameAndType { name_index: u16, descriptor_index: u16 },
+    MethodHandle { reference_kind: u8, reference_index: u16 },
+    MethodType(u16),
+    Dynamic { bootstrap_method_attr_index: u16, name_and_type_index: u16 },
+    InvokeDynamic { bootstrap_method_attr_index: u16, name_and_type_index: u16 },
+    Module(u16),
+    Package(u16),
+}
+
+///
+/// A field or method.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MemberInfo {
+    pub access_flags: u16,
+    pub name_index: u16,
+    pub descriptor_index: u16,
+    pub attributes: Vec<AttributeInfo>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AttributeInfo {
+    pub name_index: u16,
+    pub info: Vec<u8>,
+}
+
+fn invalid(msg: &str) -> Error {
+    return Error::new(ErrorKind::InvalidData, msg.to_string());
+}
+
+impl Constant {
+
+    ///
+    /// Returns the tag of the constant or None for Constant::Unusable.
+    ///
+    pub fn tag(&self) -> Option<u8> {
+        return Some(match self {
+            Constant::Unusable => return None,
+            Constant::Utf8(_) => CONSTANT_UTF8,
+            Constant::Integer(_) => CONSTANT_INTEGER,
+            Constant::Float(_) => CONSTANT_FLOAT,
+            Constant::Long(_) => CONSTANT_LONG,
+            Constant::Double(_) => CONSTANT_DOUBLE,
+            Constant::Class(_) => CONSTANT_CLASS,
+            Constant::String(_) => CONSTANT_STRING,
+            Constant::FieldRef { .. } => CONSTANT_FIELDREF,
+            Constant::MethodRef { .. } => CONSTANT_METHODREF,
+            Constant::InterfaceMethodRef { .. } => CONSTANT_INTERFACE_METHODREF,
+            Constant::NameAndType { .. } => CONSTANT_NAME_AND_TYPE,
+            Constant::MethodHandle { .. } => CONSTANT_METHOD_HANDLE,
+            Constant::MethodType(_) => CONSTANT_METHOD_TYPE,
+            Constant::Dynamic { .. } => CONSTANT_DYNAMIC,
+            Constant::InvokeDynamic { .. } => CONSTANT_INVOKE_DYNAMIC,
+            Constant::Module(_) => CONSTANT_MODULE,
+            Constant::Package(_) => CONSTANT_PACKAGE,
+        });
+    }
+}
+
+impl ClassFile {
+
+    ///
+    /// Returns the constant at the given constant pool index.
+    /// Returns None if the index is out of bounds or refers to Constant::Unusable.
+    ///
+    pub fn constant(&self, index: u16) -> Option<&Constant> {
+        return match self.constant_pool.get(index as usize) {
+            None | Some(Constant::Unusable) => None,
+            Some(constant) => Some(constant)
+        };
+    }
+
+    ///
+    /// Decodes the Utf8 constant at the given index.
+    /// Fails if the index does not refer to a Utf8 constant or if it is not valid java modified utf-8.
+    ///
+    pub fn utf8(&self, index: u16) -> io::Result<String> {
+        return match self.constant(index) {
+            Some(Constant::Utf8(data)) => Cursor::new(data.as_slice()).read_java_modified_utf8(data.len()),
+            _ => Err(invalid("constant pool index does not refer to a utf8 constant"))
+        };
+    }
+
+    ///
+    /// Returns the internal name (i.e. java/lang/Object) of the Class constant at the given index.
+    ///
+    pub fn class_name(&self, index: u16) -> io::Result<String> {
+        return match self.constant(index) {
+            Some(Constant::Class(name_index)) => self.utf8(*name_index),
+            _ => Err(invalid("constant pool index does not refer to a class constant"))
+        };
+    }
+
+    ///
+    /// Returns the internal name of this class.
+    ///
+    pub fn this_class_name(&self) -> io::Result<String> {
+        return self.class_name(self.this_class);
+    }
+
+    ///
+    /// Returns the internal name of the super class or None if the class has no super class.
+    ///
+    pub fn super_class_name(&self) -> io::Result<Option<String>> {
+        if self.super_class == 0 {
+            return Ok(None);
+        }
+
+        return self.class_name(self.super_class).map(Some);
+    }
+
+    ///
+    /// Returns the first attribute with the given name. Use with the attributes of the class, a field or a method.
+    ///
+    pub fn find_attribute<'a>(&self, attributes: &'a [AttributeInfo], name: &str) -> Option<&'a AttributeInfo> {
+        return attributes.iter().find(|a| match self.constant(a.name_index) {
+            Some(Constant::Utf8(data)) => data.as_slice() == name.as_bytes(),
+            _ => false
+        });
+    }
+}
+
+fn read_constant(mut reader: &mut dyn Read) -> io::Result<Constant> {
+    let tag = reader.read_u8()?;
+    return Ok(match tag {
+        CONSTANT_UTF8 => {
+            let size = reader.read_u16_be()? as u64;
+            let mut data = Vec::new();
+            reader.take(size).read_to_end(&mut data)?;
+            if data.len() as u64 != size {
+                return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+            }
+            Constant::Utf8(data)
+        }
+        CONSTANT_INTEGER => Constant::Integer(reader.read_i32_be()?),
+        CONSTANT_FLOAT => Constant::Float(reader.read_f32_be()?),
+        CONSTANT_LONG => Constant::Long(reader.read_i64_be()?),
+        CONSTANT_DOUBLE => Constant::Double(reader.read_f64_be()?),
+        CONSTANT_CLASS => Constant::Class(reader.read_u16_be()?),
+        CONSTANT_STRING => Constant::String(reader.read_u16_be()?),
+        CONSTANT_FIELDREF => Constant::FieldRef { class_index: reader.read_u16_be()?, name_and_type_index: reader.read_u16_be()? },
+        CONSTANT_METHODREF => Constant::MethodRef { class_index: reader.read_u16_be()?, name_and_type_index: reader.read_u16_be()? },
+        CONSTANT_INTERFACE_METHODREF => Constant::InterfaceMethodRef { class_index: reader.read_u16_be()?, name_and_type_index: reader.read_u16_be()? },
+        CONSTANT_NAME_AND_TYPE => Constant::NameAndType { name_index: reader.read_u16_be()?, descriptor_index: reader.read_u16_be()? },
+        CONSTANT_METHOD_HANDLE => Constant::MethodHandle { reference_kind: reader.read_u8()?, reference_index: reader.read_u16_be()? },
+        CONSTANT_METHOD_TYPE => Constant::MethodType(reader.read_u16_be()?),
+        CONSTANT_DYNAMIC => Constant::Dynamic { bootstrap_method_attr_index: reader.read_u16_be()?, name_and_type_index: reader.read_u16_be()? },
+        CONSTANT_INVOKE_DYNAMIC => Constant::InvokeDynamic { bootstrap_method_attr_index: reader.read_u16_be()?, name_and_type_index: reader.read_u16_be()? },
+        CONSTANT_MODULE => Constant::Module(reader.read_u16_be()?),
+        CONSTANT_PACKAGE => Constant::Package(reader.read_u16_be()?),
+        _ => return Err(invalid("invalid constant pool tag"))
+    });
+}
+
+fn write_constant(mut writer: &mut dyn Write, constant: &Constant) -> io::Result<()> {
+    let tag = match constant.tag() {
+        Some(tag) => tag,
+        None => return Ok(())
+    };
+
+    writer.write_u8(tag)?;
+    return match constant {
+        Constant::Unusable => Ok(()),
+        Constant::Utf8(data) => {
+            check_length(data.len() as u64, u16::MAX as u64)?;
+            writer.write_u16_be(data.len() as u16)?;
+            writer.write_all(data.as_slice())
+        }
+        Constant::Integer(v) => writer.write_i32_be(*v),
+        Constant::Float(v) => writer.write_f32_be(*v),
+        Constant::Long(v) => writer.write_i64_be(*v),
+        Constant::Double(v) => writer.write_f64_be(*v),
+        Constant::Class(index) | Constant::String(index) | Constant::MethodType(index)
+            | Constant::Module(index) | Constant::Package(index) => writer.write_u16_be(*index),
+        Constant::FieldRef { class_index, name_and_type_index }
+            | Constant::MethodRef { class_index, name_and_type_index }
+            | Constant::InterfaceMethodRef { class_index, name_and_type_index } => {
+            writer.write_u16_be(*class_index)?;
+            writer.write_u16_be(*name_and_type_index)
+        }
+        Constant::NameAndType { name_index, descriptor_index } => {
+            writer.write_u16_be(*name_index)?;
+            writer.write_u16_be(*descriptor_index)
+        }
+        Constant::MethodHandle { reference_kind, reference_index } => {
+            writer.write_u8(*reference_kind)?;
+            writer.write_u16_be(*reference_index)
+        }
+        Constant::Dynamic { bootstrap_method_attr_index, name_and_type_index }
+            | Constant::InvokeDynamic { bootstrap_method_attr_index, name_and_type_index } => {
+            writer.write_u16_be(*bootstrap_method_attr_index)?;
+            writer.write_u16_be(*name_and_type_index)
+        }
+    };
+}
+
+fn read_attributes(mut reader: &mut dyn Read) -> io::Result<Vec<AttributeInfo>> {
+    let count = reader.read_u16_be()?;
+    let mut attributes = Vec::with_capacity(count as usize);
+    for _ in 0 .. count {
+        let name_index = reader.read_u16_be()?;
+        let size = reader.read_u32_be()? as u64;
+        //The size is not trusted for pre allocation as a malicious class file could cause huge allocations.
+        let mut info = Vec::new();
+        reader.take(size).read_to_end(&mut info)?;
+        if info.len() as u64 != size {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+        }
+        attributes.push(AttributeInfo { name_index, info });
+    }
+
+    return Ok(attributes);
+}
+
+fn write_attributes(mut writer: &mut dyn Write, attributes: &[AttributeInfo]) -> io::Result<()> {
+    check_length(attributes.len() as u64, u16::MAX as u64)?;
+
+    writer.write_u16_be(attributes.len() as u16)?;
+    for attribute in attributes {
+        check_length(attribute.info.len() as u64, u32::MAX as u64)?;
+        writer.write_u16_be(attribute.name_index)?;
+        writer.write_u32_be(attribute.info.len() as u32)?;
+        writer.write_all(attribute.info.as_slice())?;
+    }
+
+    return Ok(());
+}
+
+fn read_members(mut reader: &mut dyn Read) -> io::Result<Vec<MemberInfo>> {
+    let count = reader.read_u16_be()?;
+    let mut members = Vec::with_capacity(count as usize);
+    for _ in 0 .. count {
+        members.push(MemberInfo {
+            access_flags: reader.read_u16_be()?,
+            name_index: reader.read_u16_be()?,
+            descriptor_index: reader.read_u16_be()?,
+            attributes: read_attributes(reader).field("attributes")?,
+        });
+    }
+
+    return Ok(members);
+}
+
+fn write_members(mut writer: &mut dyn Write, members: &[MemberInfo]) -> io::Result<()> {
+    check_length(members.len() as u64, u16::MAX as u64)?;
+
+    writer.write_u16_be(members.len() as u16)?;
+    for member in members {
+        writer.write_u16_be(member.access_flags)?;
+        writer.write_u16_be(member.name_index)?;
+        writer.write_u16_be(member.descriptor_index)?;
+        write_attributes(writer, member.attributes.as_slice())?;
+    }
+
+    return Ok(());
+}
+
+impl FromRead for ClassFile {
+
+    ///
+    /// Reads the class file. The offset of errors is relative to the position of the reader when this method is called.
+    ///
+    fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()> {
+        return CountingReader::new(reader).track_offset(|reader| self.read_class_file(reader));
+    }
+}
+
+impl ClassFile {
+    fn read_class_file(&mut self, mut reader: &mut dyn Read) -> io::Result<()> {
+        if reader.read_u32_be()? != CLASS_MAGIC {
+            return Err(invalid("invalid class file magic"));
+        }
+
+        self.minor_version = reader.read_u16_be()?;
+        self.major_version = reader.read_u16_be()?;
+
+        let count = reader.read_u16_be()? as usize;
+        if count == 0 {
+            return Err(invalid("constant pool count is 0"));
+        }
+
+        let mut constant_pool = Vec::with_capacity(count);
+        constant_pool.push(Constant::Unusable);
+        while constant_pool.len() < count {
+            let constant = read_constant(reader).field("constant_pool")?;
+            let wide = matches!(constant, Constant::Long(_) | Constant::Double(_));
+            constant_pool.push(constant);
+            if wide {
+                if constant_pool.len() >= count {
+                    return Err(invalid("long or double constant at the end of the constant pool"));
+                }
+                constant_pool.push(Constant::Unusable);
+            }
+        }
+        self.constant_pool = constant_pool;
+
+        self.access_flags = reader.read_u16_be()?;
+        self.this_class = reader.read_u16_be()?;
+        self.super_class = reader.read_u16_be()?;
+        let count = reader.read_u16_be()?;
+        let mut interfaces = Vec::with_capacity(count as usize);
+        for _ in 0 .. count {
+            interfaces.push(reader.read_u16_be().field("interfaces")?);
+        }
+        self.interfaces = interfaces;
+        self.fields = read_members(reader).field("fields")?;
+        self.methods = read_members(reader).field("methods")?;
+        self.attributes = read_attributes(reader).field("attributes")?;
+        return Ok(());
+    }
+}
+
+impl ToWrite for ClassFile {
+    fn copy_to_write(&self, mut writer: &mut dyn Write) -> io::Result<()> {
+        check_length(self.constant_pool.len() as u64, u16::MAX as u64)?;
+
+        writer.write_u32_be(CLASS_MAGIC)?;
+        writer.write_u16_be(self.minor_version)?;
+        writer.write_u16_be(self.major_version)?;
+
+        writer.write_u16_be(self.constant_pool.len().max(1) as u16)?;
+        let mut skip_next = true;
+        for constant in self.constant_pool.iter() {
+            if skip_next {
+                if constant != &Constant::Unusable {
+                    return Err(Error::new(ErrorKind::InvalidInput, "constant pool index that must be unusable holds a constant"));
+                }
+                skip_next = false;
+                continue;
+            }
+
+            if constant == &Constant::Unusable {
+                return Err(Error::new(ErrorKind::InvalidInput, "unusable constant pool entry at a usable index"));
+            }
+
+            write_constant(writer, constant)?;
+            skip_next = matches!(constant, Constant::Long(_) | Constant::Double(_));
+        }
+
+        if skip_next && !self.constant_pool.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "long or double constant at the end of the constant pool"));
+        }
+
+        writer.write_u16_be(self.access_flags)?;
+        writer.write_u16_be(self.this_class)?;
+        writer.write_u16_be(self.super_class)?;
+        check_length(self.interfaces.len() as u64, u16::MAX as u64)?;
+        writer.write_u16_be(self.interfaces.len() as u16)?;
+        for interface in self.interfaces.iter() {
+            writer.write_u16_be(*interface)?;
+        }
+        write_members(writer, self.fields.as_slice())?;
+        write_members(writer, self.methods.as_slice())?;
+        write_attributes(writer, self.attributes.as_slice())?;
+        return Ok(());
+    }
+}
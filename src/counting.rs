@@ -0,0 +1,176 @@
+use std::io;
+use std::io::{BufRead, Cursor, Read, Write};
+use crate::error::ResultExt;
+
+///
+/// Trait for readers and writers that know their current position in the stream.
+/// Implemented for CountingReader, CountingWriter and Cursor.
+///
+pub trait Position {
+
+    ///
+    /// Returns the current position in bytes.
+    ///
+    fn position(&self) -> u64;
+
+    ///
+    /// Runs the operation with self and attaches the position at which it failed to its error.
+    /// The position is the one directly after the data that caused the error.
+    /// Errors that already carry an offset are returned unchanged.
+    ///
+    fn track_offset<T, F>(&mut self, operation: F) -> io::Result<T> where Self: Sized, F: FnOnce(&mut Self) -> io::Result<T> {
+        let result = operation(self);
+        let position = self.position();
+        return result.at_offset(position);
+    }
+}
+
+impl <T> Position for Cursor<T> {
+    fn position(&self) -> u64 {
+        return Cursor::position(self);
+    }
+}
+
+impl <T: Position + ?Sized> Position for &mut T {
+    fn position(&self) -> u64 {
+        return (**self).position();
+    }
+}
+
+///
+/// Read adapter that counts the bytes read from the wrapped reader.
+///
+/// This allows using positions and alignment with readers that do not implement Seek, like sockets or pipes.
+/// If the wrapped reader implements BufRead then so does this adapter and bytes are counted when they are consumed.
+///
+#[derive(Debug)]
+pub struct CountingReader<R> {
+    inner: R,
+    count: u64,
+}
+
+impl <R> CountingReader<R> {
+
+    pub fn new(inner: R) -> CountingReader<R> {
+        return CountingReader { inner, count: 0 };
+    }
+
+    ///
+    /// Returns the amount of bytes read since creation or the last call to reset_count.
+    ///
+    pub fn position(&self) -> u64 {
+        return self.count;
+    }
+
+    ///
+    /// Resets the position to 0, which makes all following positions relative to the current one.
+    ///
+    pub fn reset_count(&mut self) {
+        self.count = 0;
+    }
+
+    pub fn get_ref(&self) -> &R {
+        return &self.inner;
+    }
+
+    ///
+    /// Returns the wrapped reader. Bytes read directly from it are not counted.
+    ///
+    pub fn get_mut(&mut self) -> &mut R {
+        return &mut self.inner;
+    }
+
+    pub fn into_inner(self) -> R {
+        return self.inner;
+    }
+}
+
+impl <R> Position for CountingReader<R> {
+    fn position(&self) -> u64 {
+        return self.count;
+    }
+}
+
+impl <R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.count += count as u64;
+        return Ok(count);
+    }
+}
+
+impl <R: BufRead> BufRead for CountingReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        return self.inner.fill_buf();
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.count += amt as u64;
+    }
+}
+
+///
+/// Write adapter that counts the bytes written to the wrapped writer.
+///
+/// This allows using positions and alignment with writers that do not implement Seek, like sockets or pipes.
+///
+#[derive(Debug)]
+pub struct CountingWriter<W> {
+    inner: W,
+    count: u64,
+}
+
+impl <W> CountingWriter<W> {
+
+    pub fn new(inner: W) -> CountingWriter<W> {
+        return CountingWriter { inner, count: 0 };
+    }
+
+    ///
+    /// Returns the amount of bytes written since creation or the last call to reset_count.
+    ///
+    pub fn position(&self) -> u64 {
+        return self.count;
+    }
+
+    ///
+    /// Resets the position to 0, which makes all following positions relative to the current one.
+    ///
+    pub fn reset_count(&mut self) {
+        self.count = 0;
+    }
+
+    pub fn get_ref(&self) -> &W {
+        return &self.inner;
+    }
+
+    ///
+    /// Returns the wrapped writer. Bytes written directly to it are not counted.
+    ///
+    pub fn get_mut(&mut self) -> &mut W {
+        return &mut self.inner;
+    }
+
+    pub fn into_inner(self) -> W {
+        return self.inner;
+    }
+}
+
+impl <W> Position for CountingWriter<W> {
+    fn position(&self) -> u64 {
+        return self.count;
+    }
+}
+
+impl <W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = self.inner.write(buf)?;
+        self.count += count as u64;
+        return Ok(count);
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        return self.inner.flush();
+    }
+}
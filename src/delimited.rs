@@ -0,0 +1,75 @@
+use std::io;
+use std::io::{ErrorKind, Read};
+
+///
+/// Trait that provides methods to read a region of known length with a reader that cannot read past its end.
+/// Automatically implemented for all implementations of io::Read.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait DelimitedRead : private::Sealed {
+
+    ///
+    /// Calls body with a reader that ends after length bytes and fails if body did not read all of them.
+    /// Reading past the end of the region fails with UnexpectedEof, as does a region that is cut off by the end of the data.
+    /// Unread bytes cause an error with the kind TrailingData, the position of this reader is unspecified in that case.
+    /// Returns the result of body.
+    ///
+    fn read_delimited<T, F>(&mut self, length: u64, body: F) -> io::Result<T> where F: FnOnce(&mut dyn Read) -> io::Result<T>;
+
+    ///
+    /// Same as read_delimited except that bytes left unread by body are skipped instead of causing an error.
+    /// This is useful for formats that allow newer versions to append fields to a record.
+    ///
+    fn read_delimited_skip_trailing<T, F>(&mut self, length: u64, body: F) -> io::Result<T> where F: FnOnce(&mut dyn Read) -> io::Result<T>;
+}
+
+fn unexpected_eof() -> io::Error {
+    return io::Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer");
+}
+
+impl <R> DelimitedRead for R where R: Read {
+    fn read_delimited<T, F>(&mut self, length: u64, body: F) -> io::Result<T> where F: FnOnce(&mut dyn Read) -> io::Result<T> {
+        let mut region = self.take(length);
+        let result = body(&mut region)?;
+        let remaining = region.limit();
+        if remaining == 0 {
+            return Ok(result);
+        }
+
+        //Trailing data that is not there is a truncated region.
+        let mut probe = [0u8];
+        loop {
+            match region.read(&mut probe) {
+                Ok(0) => return Err(unexpected_eof()),
+                Ok(_) => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e)
+            }
+        }
+
+        return Err(crate::error::Error::new(crate::error::ErrorKind::TrailingData,
+            format!("{} of {} bytes of the delimited region were not read", remaining, length))
+            .with_trailing_bytes(remaining)
+            .into());
+    }
+
+    fn read_delimited_skip_trailing<T, F>(&mut self, length: u64, body: F) -> io::Result<T> where F: FnOnce(&mut dyn Read) -> io::Result<T> {
+        let mut region = self.take(length);
+        let result = body(&mut region)?;
+        let remaining = region.limit();
+        if io::copy(&mut region, &mut io::sink())? != remaining {
+            return Err(unexpected_eof());
+        }
+
+        return Ok(result);
+    }
+}
+
+mod private {
+    use std::io::Read;
+
+    impl <T> Sealed for T where T: Read {}
+    pub trait Sealed {
+
+    }
+}
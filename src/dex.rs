@@ -0,0 +1,452 @@
+use std::io;
+use std::io::{Cursor, Error, ErrorKind, Read};
+use crate::error::ResultExt;
+use crate::from_read::FromRead;
+use crate::leb128_read::Leb128Read;
+use crate::num_read::NumRead;
+use crate::string_read::BufStringRead;
+
+pub const DEX_MAGIC: [u8; 4] = *b"dex\n";
+pub const ENDIAN_CONSTANT: u32 = 0x12345678;
+pub const REVERSE_ENDIAN_CONSTANT: u32 = 0x78563412;
+
+///
+/// Value used by dex for absent indices, i.e. a class without super class.
+///
+pub const NO_INDEX: u32 = 0xFFFFFFFF;
+
+const HEADER_SIZE: u32 = 0x70;
+
+///
+/// An android dex (dalvik executable) file.
+///
+/// Dex files refer to their sections by offsets, which is why the whole file is read into memory before parsing.
+/// The id sections are resolved into vectors that are indexed by the respective index used in the dex file.
+/// Bytecode is not interpreted.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DexFile {
+    pub header: DexHeader,
+
+    ///
+    /// All strings indexed by string_idx.
+    ///
+    pub strings: Vec<String>,
+
+    ///
+    /// The string_idx of the descriptor of each type indexed by type_idx.
+    ///
+    pub type_ids: Vec<u32>,
+    pub proto_ids: Vec<ProtoId>,
+    pub field_ids: Vec<FieldId>,
+    pub method_ids: Vec<MethodId>,
+    pub class_defs: Vec<ClassDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DexHeader {
+    ///
+    /// "dex\n" followed by the 3 digit format version and a zero byte.
+    ///
+    pub magic: [u8; 8],
+    pub checksum: u32,
+    pub signature: [u8; 20],
+    pub file_size: u32,
+    pub header_size: u32,
+    pub endian_tag: u32,
+    pub link_size: u32,
+    pub link_off: u32,
+    pub map_off: u32,
+    pub string_ids_size: u32,
+    pub string_ids_off: u32,
+    pub type_ids_size: u32,
+    pub type_ids_off: u32,
+    pub proto_ids_size: u32,
+    pub proto_ids_off: u32,
+    pub field_ids_size: u32,
+    pub field_ids_off: u32,
+    pub method_ids_size: u32,
+    pub method_ids_off: u32,
+    pub class_defs_size: u32,
+    pub class_defs_off: u32,
+    pub data_size: u32,
+    pub data_off: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ProtoId {
+    pub shorty_idx: u32,
+    pub return_type_idx: u32,
+
+    ///
+    /// The type_idx of each parameter.
+    ///
+    pub parameters: Vec<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FieldId {
+    pub class_idx: u16,
+    pub type_idx: u16,
+    pub name_idx: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MethodId {
+    pub class_idx: u16,
+    pub proto_idx: u16,
+    pub name_idx: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClassDef {
+    pub class_idx: u32,
+    pub access_flags: u32,
+
+    ///
+    /// NO_INDEX if the class has no super class.
+    ///
+    pub superclass_idx: u32,
+
+    ///
+    /// The type_idx of each implemented interface.
+    ///
+    pub interfaces: Vec<u16>,
+
+    ///
+    /// NO_INDEX if the source file is unknown.
+    ///
+    pub source_file_idx: u32,
+    pub annotations_off: u32,
+
+    ///
+    /// None if the class has no fields or methods.
+    ///
+    pub class_data: Option<ClassData>,
+    pub static_values_off: u32,
+}
+
+///
+/// The content of a class_data_item. The indices are already diff decoded.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ClassData {
+    pub static_fields: Vec<EncodedField>,
+    pub instance_fields: Vec<EncodedField>,
+    pub direct_methods: Vec<EncodedMethod>,
+    pub virtual_methods: Vec<EncodedMethod>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EncodedField {
+    pub field_idx: u32,
+    pub access_flags: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EncodedMethod {
+    pub method_idx: u32,
+    pub access_flags: u32,
+
+    ///
+    /// None for abstract and native methods.
+    ///
+    pub code: Option<CodeItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CodeItem {
+    pub registers_size: u16,
+    pub ins_size: u16,
+    pub outs_size: u16,
+    pub tries_size: u16,
+    pub debug_info: Option<DebugInfo>,
+
+    ///
+    /// The raw bytecode in 16 bit code units.
+    ///
+    pub insns: Vec<u16>,
+}
+
+///
+/// The header of a debug_info_item. The line number state machine bytecode is not interpreted.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DebugInfo {
+    pub line_start: u32,
+
+    ///
+    /// The string_idx of the name of each parameter or NO_INDEX if the name is unknown.
+    ///
+    pub parameter_names: Vec<u32>,
+}
+
+fn invalid(msg: &str) -> Error {
+    return Error::new(ErrorKind::InvalidData, msg.to_string());
+}
+
+///
+/// Returns a cursor positioned at the offset of a section with the given amount of fixed size items.
+/// Fails if the section does not fit into the file.
+///
+fn section(data: &[u8], offset: u32, count: u32, item_size: u64) -> io::Result<Cursor<&[u8]>> {
+    if (offset as u64) + (count as u64) * item_size > data.len() as u64 {
+        return Err(invalid("dex section out of bounds")).at_offset(offset as u64);
+    }
+
+    let mut cursor = Cursor::new(data);
+    cursor.set_position(offset as u64);
+    return Ok(cursor);
+}
+
+fn read_string(data: &[u8], offset: u32) -> io::Result<String> {
+    let mut cursor = section(data, offset, 0, 0)?;
+    let utf16_size = cursor.read_leb128_u32()?;
+    let string = cursor.read_java_modified_utf8_zero_terminated_max_buf(usize::MAX)?;
+    if string.encode_utf16().count() != utf16_size as usize {
+        return Err(invalid("dex string length does not match its utf16 size"));
+    }
+
+    return Ok(string);
+}
+
+fn read_type_list(data: &[u8], offset: u32) -> io::Result<Vec<u16>> {
+    if offset == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut cursor = section(data, offset, 1, 4)?;
+    let size = cursor.read_u32_le()?;
+    let mut cursor = section(data, offset + 4, size, 2)?;
+    let mut list = Vec::with_capacity(size as usize);
+    for _ in 0 .. size {
+        list.push(cursor.read_u16_le()?);
+    }
+
+    return Ok(list);
+}
+
+fn read_encoded_fields(cursor: &mut Cursor<&[u8]>, count: u32) -> io::Result<Vec<EncodedField>> {
+    let mut fields = Vec::new();
+    let mut field_idx = 0u32;
+    for _ in 0 .. count {
+        field_idx = field_idx.checked_add(cursor.read_leb128_u32()?).ok_or_else(|| invalid("field_idx overflow"))?;
+        fields.push(EncodedField { field_idx, access_flags: cursor.read_leb128_u32()? });
+    }
+
+    return Ok(fields);
+}
+
+fn read_encoded_methods(data: &[u8], cursor: &mut Cursor<&[u8]>, count: u32) -> io::Result<Vec<EncodedMethod>> {
+    let mut methods = Vec::new();
+    let mut method_idx = 0u32;
+    for _ in 0 .. count {
+        method_idx = method_idx.checked_add(cursor.read_leb128_u32()?).ok_or_else(|| invalid("method_idx overflow"))?;
+        let access_flags = cursor.read_leb128_u32()?;
+        let code_off = cursor.read_leb128_u32()?;
+        let code = if code_off == 0 { None } else { Some(read_code_item(data, code_off).at_offset(code_off as u64).field("code")?) };
+        methods.push(EncodedMethod { method_idx, access_flags, code });
+    }
+
+    return Ok(methods);
+}
+
+fn read_class_data(data: &[u8], offset: u32) -> io::Result<ClassData> {
+    let mut cursor = section(data, offset, 0, 0)?;
+    let static_fields_size = cursor.read_leb128_u32()?;
+    let instance_fields_size = cursor.read_leb128_u32()?;
+    let direct_methods_size = cursor.read_leb128_u32()?;
+    let virtual_methods_size = cursor.read_leb128_u32()?;
+    return Ok(ClassData {
+        static_fields: read_encoded_fields(&mut cursor, static_fields_size).field("static_fields")?,
+        instance_fields: read_encoded_fields(&mut cursor, instance_fields_size).field("instance_fields")?,
+        direct_methods: read_encoded_methods(data, &mut cursor, direct_methods_size).field("direct_methods")?,
+        virtual_methods: read_encoded_methods(data, &mut cursor, virtual_methods_size).field("virtual_methods")?,
+    });
+}
+
+fn read_code_item(data: &[u8], offset: u32) -> io::Result<CodeItem> {
+    let mut cursor = section(data, offset, 1, 16)?;
+    let registers_size = cursor.read_u16_le()?;
+    let ins_size = cursor.read_u16_le()?;
+    let outs_size = cursor.read_u16_le()?;
+    let tries_size = cursor.read_u16_le()?;
+    let debug_info_off = cursor.read_u32_le()?;
+    let insns_size = cursor.read_u32_le()?;
+    let mut cursor = section(data, offset + 16, insns_size, 2)?;
+    let mut insns = Vec::with_capacity(insns_size as usize);
+    for _ in 0 .. insns_size {
+        insns.push(cursor.read_u16_le()?);
+    }
+
+    let debug_info = if debug_info_off == 0 { None } else { Some(read_debug_info(data, debug_info_off).at_offset(debug_info_off as u64).field("debug_info")?) };
+    return Ok(CodeItem { registers_size, ins_size, outs_size, tries_size, debug_info, insns });
+}
+
+fn read_debug_info(data: &[u8], offset: u32) -> io::Result<DebugInfo> {
+    let mut cursor = section(data, offset, 0, 0)?;
+    let line_start = cursor.read_leb128_u32()?;
+    let parameters_size = cursor.read_leb128_u32()?;
+    let mut parameter_names = Vec::new();
+    for _ in 0 .. parameters_size {
+        parameter_names.push(cursor.read_leb128p1_u32()?);
+    }
+
+    return Ok(DebugInfo { line_start, parameter_names });
+}
+
+fn read_header(data: &[u8]) -> io::Result<DexHeader> {
+    let mut cursor = section(data, 0, 1, HEADER_SIZE as u64)?;
+    let mut header = DexHeader::default();
+    cursor.read_exact(&mut header.magic)?;
+    if header.magic[0..4] != DEX_MAGIC || header.magic[7] != 0 {
+        return Err(invalid("invalid dex magic"));
+    }
+
+    header.checksum = cursor.read_u32_le()?;
+    cursor.read_exact(&mut header.signature)?;
+    header.file_size = cursor.read_u32_le()?;
+    header.header_size = cursor.read_u32_le()?;
+    header.endian_tag = cursor.read_u32_le()?;
+    if header.endian_tag == REVERSE_ENDIAN_CONSTANT {
+        return Err(Error::new(ErrorKind::Unsupported, "big endian dex files are not supported"));
+    }
+
+    if header.endian_tag != ENDIAN_CONSTANT {
+        return Err(invalid("invalid dex endian tag"));
+    }
+
+    if header.header_size < HEADER_SIZE {
+        return Err(invalid("dex header too small"));
+    }
+
+    header.link_size = cursor.read_u32_le()?;
+    header.link_off = cursor.read_u32_le()?;
+    header.map_off = cursor.read_u32_le()?;
+    header.string_ids_size = cursor.read_u32_le()?;
+    header.string_ids_off = cursor.read_u32_le()?;
+    header.type_ids_size = cursor.read_u32_le()?;
+    header.type_ids_off = cursor.read_u32_le()?;
+    header.proto_ids_size = cursor.read_u32_le()?;
+    header.proto_ids_off = cursor.read_u32_le()?;
+    header.field_ids_size = cursor.read_u32_le()?;
+    header.field_ids_off = cursor.read_u32_le()?;
+    header.method_ids_size = cursor.read_u32_le()?;
+    header.method_ids_off = cursor.read_u32_le()?;
+    header.class_defs_size = cursor.read_u32_le()?;
+    header.class_defs_off = cursor.read_u32_le()?;
+    header.data_size = cursor.read_u32_le()?;
+    header.data_off = cursor.read_u32_le()?;
+    return Ok(header);
+}
+
+impl DexFile {
+
+    ///
+    /// Parses a complete dex file.
+    ///
+    pub fn parse(data: &[u8]) -> io::Result<DexFile> {
+        let header = read_header(data).field("header")?;
+
+        let mut cursor = section(data, header.string_ids_off, header.string_ids_size, 4)?;
+        let mut strings = Vec::with_capacity(header.string_ids_size as usize);
+        for _ in 0 .. header.string_ids_size {
+            let string_data_off = cursor.read_u32_le().field("string_ids")?;
+            strings.push(read_string(data, string_data_off).at_offset(string_data_off as u64).field("strings")?);
+        }
+
+        let mut cursor = section(data, header.type_ids_off, header.type_ids_size, 4)?;
+        let mut type_ids = Vec::with_capacity(header.type_ids_size as usize);
+        for _ in 0 .. header.type_ids_size {
+            type_ids.push(cursor.read_u32_le()?);
+        }
+
+        let mut cursor = section(data, header.proto_ids_off, header.proto_ids_size, 12)?;
+        let mut proto_ids = Vec::with_capacity(header.proto_ids_size as usize);
+        for _ in 0 .. header.proto_ids_size {
+            proto_ids.push(ProtoId {
+                shorty_idx: cursor.read_u32_le()?,
+                return_type_idx: cursor.read_u32_le()?,
+                parameters: read_type_list(data, cursor.read_u32_le()?).field("parameters").field("proto_ids")?,
+            });
+        }
+
+        let mut cursor = section(data, header.field_ids_off, header.field_ids_size, 8)?;
+        let mut field_ids = Vec::with_capacity(header.field_ids_size as usize);
+        for _ in 0 .. header.field_ids_size {
+            field_ids.push(FieldId {
+                class_idx: cursor.read_u16_le()?,
+                type_idx: cursor.read_u16_le()?,
+                name_idx: cursor.read_u32_le()?,
+            });
+        }
+
+        let mut cursor = section(data, header.method_ids_off, header.method_ids_size, 8)?;
+        let mut method_ids = Vec::with_capacity(header.method_ids_size as usize);
+        for _ in 0 .. header.method_ids_size {
+            method_ids.push(MethodId {
+                class_idx: cursor.read_u16_le()?,
+                proto_idx: cursor.read_u16_le()?,
+                name_idx: cursor.read_u32_le()?,
+            });
+        }
+
+        let mut cursor = section(data, header.class_defs_off, header.class_defs_size, 32)?;
+        let mut class_defs = Vec::with_capacity(header.class_defs_size as usize);
+        for _ in 0 .. header.class_defs_size {
+            let class_idx = cursor.read_u32_le()?;
+            let access_flags = cursor.read_u32_le()?;
+            let superclass_idx = cursor.read_u32_le()?;
+            let interfaces = read_type_list(data, cursor.read_u32_le()?).field("interfaces").field("class_defs")?;
+            let source_file_idx = cursor.read_u32_le()?;
+            let annotations_off = cursor.read_u32_le()?;
+            let class_data_off = cursor.read_u32_le()?;
+            let class_data = if class_data_off == 0 { None } else {
+                Some(read_class_data(data, class_data_off).at_offset(class_data_off as u64).field("class_data").field("class_defs")?)
+            };
+            let static_values_off = cursor.read_u32_le()?;
+            class_defs.push(ClassDef {
+                class_idx, access_flags, superclass_idx, interfaces, source_file_idx, annotations_off, class_data, static_values_off,
+            });
+        }
+
+        return Ok(DexFile { header, strings, type_ids, proto_ids, field_ids, method_ids, class_defs });
+    }
+
+    ///
+    /// Returns the string with the given string_idx.
+    ///
+    pub fn string(&self, string_idx: u32) -> Option<&str> {
+        return self.strings.get(string_idx as usize).map(|s| s.as_str());
+    }
+
+    ///
+    /// Returns the descriptor (i.e. Ljava/lang/Object;) of the type with the given type_idx.
+    ///
+    pub fn type_descriptor(&self, type_idx: u32) -> Option<&str> {
+        return self.string(*self.type_ids.get(type_idx as usize)?);
+    }
+
+    ///
+    /// Returns the name of the field with the given field_idx.
+    ///
+    pub fn field_name(&self, field_idx: u32) -> Option<&str> {
+        return self.string(self.field_ids.get(field_idx as usize)?.name_idx);
+    }
+
+    ///
+    /// Returns the name of the method with the given method_idx.
+    ///
+    pub fn method_name(&self, method_idx: u32) -> Option<&str> {
+        return self.string(self.method_ids.get(method_idx as usize)?.name_idx);
+    }
+}
+
+impl FromRead for DexFile {
+    fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        *self = DexFile::parse(data.as_slice())?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,243 @@
+use std::io;
+use std::io::{Error, ErrorKind, Read};
+use crate::dotnet_types::DotNetDecimal;
+use crate::num_read::NumRead;
+use crate::string_read::StringRead;
+
+///
+/// Trait that provides the methods of System.IO.BinaryReader with the exact same semantics.
+/// All values are read in little endian byte order and strings/chars are utf-8 encoded,
+/// which is the default encoding of BinaryReader.
+/// The position of an arbitrary reader is unknown, use Position::track_offset of a Cursor or CountingReader
+/// to attach the offset to errors.
+/// Automatically implemented for all implementations of io::Read.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait DotNetBinaryRead : private::Sealed {
+
+    ///
+    /// BinaryReader#ReadBoolean
+    /// Reads one byte and treats 0 as false and any other value as true.
+    ///
+    fn read_dotnet_boolean(&mut self) -> io::Result<bool>;
+
+    ///
+    /// BinaryReader#ReadByte
+    ///
+    fn read_dotnet_byte(&mut self) -> io::Result<u8>;
+
+    ///
+    /// BinaryReader#ReadSByte
+    ///
+    fn read_dotnet_sbyte(&mut self) -> io::Result<i8>;
+
+    ///
+    /// BinaryReader#ReadInt16
+    ///
+    fn read_dotnet_int16(&mut self) -> io::Result<i16>;
+
+    ///
+    /// BinaryReader#ReadUInt16
+    ///
+    fn read_dotnet_uint16(&mut self) -> io::Result<u16>;
+
+    ///
+    /// BinaryReader#ReadInt32
+    ///
+    fn read_dotnet_int32(&mut self) -> io::Result<i32>;
+
+    ///
+    /// BinaryReader#ReadUInt32
+    ///
+    fn read_dotnet_uint32(&mut self) -> io::Result<u32>;
+
+    ///
+    /// BinaryReader#ReadInt64
+    ///
+    fn read_dotnet_int64(&mut self) -> io::Result<i64>;
+
+    ///
+    /// BinaryReader#ReadUInt64
+    ///
+    fn read_dotnet_uint64(&mut self) -> io::Result<u64>;
+
+    ///
+    /// BinaryReader#ReadSingle
+    ///
+    fn read_dotnet_single(&mut self) -> io::Result<f32>;
+
+    ///
+    /// BinaryReader#ReadDouble
+    ///
+    fn read_dotnet_double(&mut self) -> io::Result<f64>;
+
+    ///
+    /// BinaryReader#ReadDecimal
+    /// Fails with InvalidData if the flags of the decimal are invalid.
+    ///
+    fn read_dotnet_decimal(&mut self) -> io::Result<DotNetDecimal>;
+
+    ///
+    /// BinaryReader#ReadChar
+    /// Reads a single utf-8 encoded char. A .NET char is a single utf-16 code unit,
+    /// chars outside the basic multilingual plane can therefore not be read and cause an error.
+    /// Fails with InvalidData if the data is not valid utf-8.
+    ///
+    fn read_dotnet_char(&mut self) -> io::Result<char>;
+
+    ///
+    /// BinaryReader#ReadString
+    /// Reads a 7 bit encoded length in bytes followed by utf-8 data.
+    /// Invalid utf-8 sequences are replaced with U+FFFD just like .NET does.
+    ///
+    fn read_dotnet_string(&mut self) -> io::Result<String>;
+
+    ///
+    /// BinaryReader#Read7BitEncodedInt
+    /// Fails if the value does not fit into 32 bits. Values larger than i32::MAX are returned as negative numbers.
+    ///
+    fn read_dotnet_7bit_encoded_int(&mut self) -> io::Result<i32>;
+
+    ///
+    /// BinaryReader#Read7BitEncodedInt64
+    /// Fails if the value does not fit into 64 bits. Values larger than i64::MAX are returned as negative numbers.
+    ///
+    fn read_dotnet_7bit_encoded_int64(&mut self) -> io::Result<i64>;
+}
+
+fn too_many_bytes() -> Error {
+    return crate::error::Error::new(crate::error::ErrorKind::Overflow, "too many bytes in what should have been a 7 bit encoded integer").into();
+}
+
+impl <T> DotNetBinaryRead for T where T: Read {
+    fn read_dotnet_boolean(&mut self) -> io::Result<bool> {
+        return self.read_bool();
+    }
+
+    fn read_dotnet_byte(&mut self) -> io::Result<u8> {
+        return self.read_u8();
+    }
+
+    fn read_dotnet_sbyte(&mut self) -> io::Result<i8> {
+        return self.read_i8();
+    }
+
+    fn read_dotnet_int16(&mut self) -> io::Result<i16> {
+        return self.read_i16_le();
+    }
+
+    fn read_dotnet_uint16(&mut self) -> io::Result<u16> {
+        return self.read_u16_le();
+    }
+
+    fn read_dotnet_int32(&mut self) -> io::Result<i32> {
+        return self.read_i32_le();
+    }
+
+    fn read_dotnet_uint32(&mut self) -> io::Result<u32> {
+        return self.read_u32_le();
+    }
+
+    fn read_dotnet_int64(&mut self) -> io::Result<i64> {
+        return self.read_i64_le();
+    }
+
+    fn read_dotnet_uint64(&mut self) -> io::Result<u64> {
+        return self.read_u64_le();
+    }
+
+    fn read_dotnet_single(&mut self) -> io::Result<f32> {
+        return self.read_f32_le();
+    }
+
+    fn read_dotnet_double(&mut self) -> io::Result<f64> {
+        return self.read_f64_le();
+    }
+
+    fn read_dotnet_decimal(&mut self) -> io::Result<DotNetDecimal> {
+        let value = DotNetDecimal {
+            lo: self.read_u32_le()?,
+            mid: self.read_u32_le()?,
+            hi: self.read_u32_le()?,
+            flags: self.read_u32_le()?,
+        };
+
+        if !value.is_valid() {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid decimal flags"));
+        }
+
+        return Ok(value);
+    }
+
+    fn read_dotnet_char(&mut self) -> io::Result<char> {
+        let value = self.read_char_utf8()?;
+        if value as u32 > 0xFFFF {
+            return Err(Error::new(ErrorKind::InvalidData, "char outside of the basic multilingual plane cannot be read as a single .NET char"));
+        }
+
+        return Ok(value);
+    }
+
+    fn read_dotnet_string(&mut self) -> io::Result<String> {
+        let size = self.read_dotnet_7bit_encoded_int()?;
+        if size < 0 {
+            return Err(Error::new(ErrorKind::InvalidData, "invalid string length"));
+        }
+
+        let mut data = Vec::new();
+        self.take(size as u64).read_to_end(&mut data)?;
+        if data.len() != size as usize {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        return Ok(match String::from_utf8(data) {
+            Ok(string) => string,
+            Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned()
+        });
+    }
+
+    fn read_dotnet_7bit_encoded_int(&mut self) -> io::Result<i32> {
+        let mut result = 0u32;
+        for shift in [0u32, 7, 14, 21].iter() {
+            let next = self.read_u8()?;
+            result |= ((next & 0x7F) as u32) << shift;
+            if next & 0x80 == 0 {
+                return Ok(result as i32);
+            }
+        }
+
+        let next = self.read_u8()?;
+        if next > 0x0F {
+            return Err(too_many_bytes());
+        }
+
+        return Ok((result | ((next as u32) << 28)) as i32);
+    }
+
+    fn read_dotnet_7bit_encoded_int64(&mut self) -> io::Result<i64> {
+        let mut result = 0u64;
+        for shift in (0u32..63).step_by(7) {
+            let next = self.read_u8()?;
+            result |= ((next & 0x7F) as u64) << shift;
+            if next & 0x80 == 0 {
+                return Ok(result as i64);
+            }
+        }
+
+        let next = self.read_u8()?;
+        if next > 0x01 {
+            return Err(too_many_bytes());
+        }
+
+        return Ok((result | ((next as u64) << 63)) as i64);
+    }
+}
+
+mod private {
+    use std::io::Read;
+
+    impl <T> Sealed for T where T: Read {}
+    pub trait Sealed {
+
+    }
+}
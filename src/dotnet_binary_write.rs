@@ -0,0 +1,206 @@
+use std::io;
+use std::io::{Error, ErrorKind, Write};
+use crate::dotnet_types::DotNetDecimal;
+use crate::num_write::NumWrite;
+use crate::error::check_length;
+use crate::string_write::StringWrite;
+
+///
+/// Trait that provides the methods of System.IO.BinaryWriter with the exact same semantics.
+/// All values are written in little endian byte order and strings/chars are utf-8 encoded,
+/// which is the default encoding of BinaryWriter.
+/// Automatically implemented for all implementations of io::Write.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait DotNetBinaryWrite : private::Sealed {
+
+    ///
+    /// BinaryWriter#Write(bool)
+    /// Writes 1 for true and 0 for false.
+    ///
+    fn write_dotnet_boolean(&mut self, value: bool) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(byte)
+    ///
+    fn write_dotnet_byte(&mut self, value: u8) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(sbyte)
+    ///
+    fn write_dotnet_sbyte(&mut self, value: i8) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(short)
+    ///
+    fn write_dotnet_int16(&mut self, value: i16) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(ushort)
+    ///
+    fn write_dotnet_uint16(&mut self, value: u16) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(int)
+    ///
+    fn write_dotnet_int32(&mut self, value: i32) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(uint)
+    ///
+    fn write_dotnet_uint32(&mut self, value: u32) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(long)
+    ///
+    fn write_dotnet_int64(&mut self, value: i64) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(ulong)
+    ///
+    fn write_dotnet_uint64(&mut self, value: u64) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(float)
+    ///
+    fn write_dotnet_single(&mut self, value: f32) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(double)
+    ///
+    fn write_dotnet_double(&mut self, value: f64) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(decimal)
+    /// Fails with InvalidInput if the flags of the decimal are invalid.
+    ///
+    fn write_dotnet_decimal(&mut self, value: &DotNetDecimal) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(char)
+    /// Writes the char utf-8 encoded. A .NET char is a single utf-16 code unit,
+    /// chars outside the basic multilingual plane can therefore not be written and cause an error.
+    ///
+    fn write_dotnet_char(&mut self, value: char) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write(string)
+    /// Writes the length of the utf-8 encoded string in bytes as 7 bit encoded int followed by the utf-8 data.
+    /// Fails with an Overflow error if the string is longer than i32::MAX bytes.
+    ///
+    fn write_dotnet_string(&mut self, string: &str) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write7BitEncodedInt
+    /// Negative values are written as their unsigned two's complement and always take 5 bytes.
+    ///
+    fn write_dotnet_7bit_encoded_int(&mut self, value: i32) -> io::Result<()>;
+
+    ///
+    /// BinaryWriter#Write7BitEncodedInt64
+    /// Negative values are written as their unsigned two's complement and always take 10 bytes.
+    ///
+    fn write_dotnet_7bit_encoded_int64(&mut self, value: i64) -> io::Result<()>;
+}
+
+impl <T> DotNetBinaryWrite for T where T: Write {
+    fn write_dotnet_boolean(&mut self, value: bool) -> io::Result<()> {
+        return self.write_bool(value);
+    }
+
+    fn write_dotnet_byte(&mut self, value: u8) -> io::Result<()> {
+        return self.write_u8(value);
+    }
+
+    fn write_dotnet_sbyte(&mut self, value: i8) -> io::Result<()> {
+        return self.write_i8(value);
+    }
+
+    fn write_dotnet_int16(&mut self, value: i16) -> io::Result<()> {
+        return self.write_i16_le(value);
+    }
+
+    fn write_dotnet_uint16(&mut self, value: u16) -> io::Result<()> {
+        return self.write_u16_le(value);
+    }
+
+    fn write_dotnet_int32(&mut self, value: i32) -> io::Result<()> {
+        return self.write_i32_le(value);
+    }
+
+    fn write_dotnet_uint32(&mut self, value: u32) -> io::Result<()> {
+        return self.write_u32_le(value);
+    }
+
+    fn write_dotnet_int64(&mut self, value: i64) -> io::Result<()> {
+        return self.write_i64_le(value);
+    }
+
+    fn write_dotnet_uint64(&mut self, value: u64) -> io::Result<()> {
+        return self.write_u64_le(value);
+    }
+
+    fn write_dotnet_single(&mut self, value: f32) -> io::Result<()> {
+        return self.write_f32_le(value);
+    }
+
+    fn write_dotnet_double(&mut self, value: f64) -> io::Result<()> {
+        return self.write_f64_le(value);
+    }
+
+    fn write_dotnet_decimal(&mut self, value: &DotNetDecimal) -> io::Result<()> {
+        if !value.is_valid() {
+            return Err(Error::new(ErrorKind::InvalidInput, "invalid decimal flags"));
+        }
+
+        self.write_u32_le(value.lo)?;
+        self.write_u32_le(value.mid)?;
+        self.write_u32_le(value.hi)?;
+        return self.write_u32_le(value.flags);
+    }
+
+    fn write_dotnet_char(&mut self, value: char) -> io::Result<()> {
+        if value as u32 > 0xFFFF {
+            return Err(Error::new(ErrorKind::InvalidInput, "char outside of the basic multilingual plane cannot be written as a single .NET char"));
+        }
+
+        self.write_char_utf8(value)?;
+        return Ok(());
+    }
+
+    fn write_dotnet_string(&mut self, string: &str) -> io::Result<()> {
+        check_length(string.len() as u64, i32::MAX as u64)?;
+
+        self.write_dotnet_7bit_encoded_int(string.len() as i32)?;
+        return self.write_all(string.as_bytes());
+    }
+
+    fn write_dotnet_7bit_encoded_int(&mut self, value: i32) -> io::Result<()> {
+        let mut value = value as u32;
+        while value > 0x7F {
+            self.write_u8((value as u8) | 0x80)?;
+            value >>= 7;
+        }
+
+        return self.write_u8(value as u8);
+    }
+
+    fn write_dotnet_7bit_encoded_int64(&mut self, value: i64) -> io::Result<()> {
+        let mut value = value as u64;
+        while value > 0x7F {
+            self.write_u8((value as u8) | 0x80)?;
+            value >>= 7;
+        }
+
+        return self.write_u8(value as u8);
+    }
+}
+
+mod private {
+    use std::io::Write;
+
+    impl <T> Sealed for T where T: Write {}
+    pub trait Sealed {
+
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::{Error, ErrorKind};
+
+const SIGN_MASK: u32 = 0x80000000;
+const SCALE_MASK: u32 = 0x00FF0000;
+const SCALE_SHIFT: u32 = 16;
+
+///
+/// The largest scale a .NET decimal can have.
+///
+pub const DECIMAL_MAX_SCALE: u8 = 28;
+
+///
+/// A .NET System.Decimal in the layout returned by decimal.GetBits and written by BinaryWriter.
+///
+/// The value is (-1)^sign * (hi:mid:lo as 96 bit integer) / 10^scale.
+/// The sign is bit 31 and the scale bits 16-23 of flags, all other bits of flags must be 0.
+///
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct DotNetDecimal {
+    pub lo: u32,
+    pub mid: u32,
+    pub hi: u32,
+    pub flags: u32,
+}
+
+impl DotNetDecimal {
+
+    ///
+    /// Creates a decimal from its 96 bit mantissa, scale and sign.
+    /// Fails if the mantissa does not fit into 96 bits or the scale is larger than 28.
+    ///
+    pub fn new(mantissa: u128, scale: u8, negative: bool) -> io::Result<DotNetDecimal> {
+        if mantissa >> 96 != 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "decimal mantissa larger than 96 bits"));
+        }
+
+        if scale > DECIMAL_MAX_SCALE {
+            return Err(Error::new(ErrorKind::InvalidInput, "decimal scale larger than 28"));
+        }
+
+        let sign = if negative { SIGN_MASK } else { 0 };
+        return Ok(DotNetDecimal {
+            lo: mantissa as u32,
+            mid: (mantissa >> 32) as u32,
+            hi: (mantissa >> 64) as u32,
+            flags: sign | ((scale as u32) << SCALE_SHIFT),
+        });
+    }
+
+    ///
+    /// Returns true if the flags are valid. .NET refuses to read or construct decimals with invalid flags.
+    ///
+    pub fn is_valid(&self) -> bool {
+        return self.flags & !(SIGN_MASK | SCALE_MASK) == 0 && self.scale() <= DECIMAL_MAX_SCALE;
+    }
+
+    pub fn mantissa(&self) -> u128 {
+        return (self.lo as u128) | ((self.mid as u128) << 32) | ((self.hi as u128) << 64);
+    }
+
+    pub fn scale(&self) -> u8 {
+        return ((self.flags & SCALE_MASK) >> SCALE_SHIFT) as u8;
+    }
+
+    ///
+    /// Returns true if the sign bit is set. This is also the case for negative zero.
+    ///
+    pub fn is_negative(&self) -> bool {
+        return self.flags & SIGN_MASK != 0;
+    }
+}
+
+impl Display for DotNetDecimal {
+
+    ///
+    /// Formats the decimal like .NET's decimal.ToString with the invariant culture.
+    /// Trailing zeros implied by the scale are kept, negative zero is formatted without sign.
+    ///
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa().to_string();
+        let scale = self.scale() as usize;
+        if self.is_negative() && self.mantissa() != 0 {
+            f.write_str("-")?;
+        }
+
+        if scale == 0 {
+            return f.write_str(digits.as_str());
+        }
+
+        let padded = format!("{:0>width$}", digits, width = scale + 1);
+        let (int, frac) = padded.split_at(padded.len() - scale);
+        return write!(f, "{}.{}", int, frac);
+    }
+}
@@ -0,0 +1,25 @@
+use std::io;
+use std::io::{Chain, ErrorKind, Read};
+
+///
+/// Reads a value with the given function unless the reader is at EOF.
+///
+/// A single byte is read first to find out whether the reader is at EOF, in which case Ok(None) is returned.
+/// Otherwise the function reads the value from a reader that starts with that byte, so a value that
+/// ends prematurely fails with UnexpectedEof like it would without this probe.
+///
+pub(crate) fn try_read<R, T, F>(reader: &mut R, read: F) -> io::Result<Option<T>>
+    where R: Read + ?Sized, F: FnOnce(&mut Chain<&[u8], &mut R>) -> io::Result<T> {
+    let mut first = [0u8];
+    loop {
+        match reader.read(&mut first) {
+            Ok(0) => return Ok(None),
+            Ok(_) => break,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e)
+        }
+    }
+
+    let mut chain = (&first[..]).chain(reader);
+    return read(&mut chain).map(Some);
+}
@@ -0,0 +1,320 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+///
+/// Category of an Error.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum ErrorKind {
+    ///
+    /// A value or length does not fit into the type or field it has to be stored in.
+    ///
+    Overflow,
+
+    ///
+    /// The data is not valid in the expected encoding, for example invalid utf-8 or an unpaired surrogate.
+    ///
+    InvalidEncoding,
+
+    ///
+    /// A limit imposed by the caller or the format was exceeded, for example the maximum length of a zero terminated string.
+    ///
+    LimitExceeded,
+
+    ///
+    /// The data ended before the value was complete.
+    ///
+    UnexpectedEof,
+
+    ///
+    /// Data that should have been consumed entirely was only partially consumed.
+    ///
+    TrailingData,
+
+    ///
+    /// Padding contains a byte other than the expected padding byte.
+    ///
+    InvalidPadding,
+
+    ///
+    /// Any other error, usually an io::Error of the underlying Read or Write.
+    ///
+    Other,
+}
+
+///
+/// Error with context about where reading or writing failed.
+///
+/// Besides the kind and message it carries the offset in the stream at which the error occurred, if it is known,
+/// and the path of the fields that were being read, which is pushed by FromRead implementations using ResultExt.
+///
+/// All methods of this crate return io::Result. The io::Error of a failed call wraps this type if context was
+/// attached to it and can be converted back with From without losing anything.
+/// io::Errors that do not wrap this type are converted with the kind Other, or UnexpectedEof for ErrorKind::UnexpectedEof.
+///
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    io_kind: io::ErrorKind,
+    message: Cow<'static, str>,
+    offset: Option<u64>,
+    trailing_bytes: Option<u64>,
+    actual_length: Option<u64>,
+    max_length: Option<u64>,
+    limit: Option<u64>,
+    fields: Vec<&'static str>,
+    source: Option<io::Error>,
+}
+
+impl Error {
+
+    ///
+    /// Creates a new error without offset or field context.
+    /// When converted to an io::Error the kind UnexpectedEof becomes ErrorKind::UnexpectedEof,
+    /// Other becomes ErrorKind::Other and everything else ErrorKind::InvalidData.
+    ///
+    pub fn new<M: Into<Cow<'static, str>>>(kind: ErrorKind, message: M) -> Error {
+        let io_kind = match kind {
+            ErrorKind::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+            ErrorKind::Other => io::ErrorKind::Other,
+            _ => io::ErrorKind::InvalidData,
+        };
+
+        return Error { kind, io_kind, message: message.into(), offset: None, trailing_bytes: None,
+            actual_length: None, max_length: None, limit: None, fields: Vec::new(), source: None };
+    }
+
+    ///
+    /// Changes the kind of the io::Error this error is converted to.
+    ///
+    pub fn with_io_kind(mut self, io_kind: io::ErrorKind) -> Error {
+        self.io_kind = io_kind;
+        return self;
+    }
+
+    ///
+    /// Sets the offset at which the error occurred unless an offset is already set.
+    /// The first offset set is usually the most precise one.
+    ///
+    pub fn with_offset(mut self, offset: u64) -> Error {
+        if self.offset.is_none() {
+            self.offset = Some(offset);
+        }
+
+        return self;
+    }
+
+    ///
+    /// Sets the amount of bytes that were left unread, intended for errors of the kind TrailingData.
+    ///
+    pub fn with_trailing_bytes(mut self, count: u64) -> Error {
+        self.trailing_bytes = Some(count);
+        return self;
+    }
+
+    ///
+    /// Sets the length that did not fit, intended for errors of the kind Overflow.
+    ///
+    pub fn with_actual_length(mut self, length: u64) -> Error {
+        self.actual_length = Some(length);
+        return self;
+    }
+
+    ///
+    /// Sets the largest length that can be represented, intended for errors of the kind Overflow.
+    ///
+    pub fn with_max_length(mut self, length: u64) -> Error {
+        self.max_length = Some(length);
+        return self;
+    }
+
+    ///
+    /// Sets the limit that was exceeded, intended for errors of the kind LimitExceeded.
+    ///
+    pub fn with_limit(mut self, limit: u64) -> Error {
+        self.limit = Some(limit);
+        return self;
+    }
+
+    ///
+    /// Pushes the name of the field that contains the location of the error.
+    /// Fields are pushed from the innermost to the outermost.
+    ///
+    pub fn with_field(mut self, name: &'static str) -> Error {
+        self.fields.push(name);
+        return self;
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        return self.kind;
+    }
+
+    ///
+    /// Returns the kind of the io::Error this error is converted to.
+    ///
+    pub fn io_kind(&self) -> io::ErrorKind {
+        return self.io_kind;
+    }
+
+    pub fn message(&self) -> &str {
+        return self.message.as_ref();
+    }
+
+    pub fn offset(&self) -> Option<u64> {
+        return self.offset;
+    }
+
+    ///
+    /// Returns the amount of bytes that were left unread if the error has the kind TrailingData.
+    ///
+    pub fn trailing_bytes(&self) -> Option<u64> {
+        return self.trailing_bytes;
+    }
+
+    ///
+    /// Returns the length that did not fit if the error has the kind Overflow and the length is known.
+    ///
+    pub fn actual_length(&self) -> Option<u64> {
+        return self.actual_length;
+    }
+
+    ///
+    /// Returns the largest length that can be represented if the error has the kind Overflow and the length is known.
+    ///
+    pub fn max_length(&self) -> Option<u64> {
+        return self.max_length;
+    }
+
+    ///
+    /// Returns the limit that was exceeded if the error has the kind LimitExceeded.
+    ///
+    pub fn limit(&self) -> Option<u64> {
+        return self.limit;
+    }
+
+    ///
+    /// Returns the field names from the outermost to the innermost.
+    ///
+    pub fn fields(&self) -> Vec<&'static str> {
+        return self.fields.iter().rev().copied().collect();
+    }
+
+    ///
+    /// Returns the field names from the outermost to the innermost separated by dots.
+    ///
+    pub fn field_path(&self) -> String {
+        return self.fields().join(".");
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message.as_ref())?;
+        if !self.fields.is_empty() {
+            write!(f, " in field {}", self.field_path())?;
+        }
+
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {}", offset)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match &self.source {
+            Some(source) => Some(source),
+            None => None
+        };
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        if err.get_ref().map_or(false, |payload| payload.is::<Error>()) {
+            let payload = err.into_inner().unwrap();
+            return *payload.downcast::<Error>().unwrap();
+        }
+
+        let kind = if err.kind() == io::ErrorKind::UnexpectedEof { ErrorKind::UnexpectedEof } else { ErrorKind::Other };
+        let mut error = Error::new(kind, err.to_string()).with_io_kind(err.kind());
+        error.source = Some(err);
+        return error;
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> io::Error {
+        return io::Error::new(err.io_kind, err);
+    }
+}
+
+///
+/// Fails with an Overflow error of the io kind InvalidInput if a length is larger than the maximum its prefix or field can represent.
+///
+#[cfg(any(feature = "string_write", feature = "classfile", feature = "length_prefix"))]
+pub(crate) fn check_length(actual: u64, max: u64) -> io::Result<()> {
+    if actual > max {
+        return Err(Error::new(ErrorKind::Overflow, format!("length {} exceeds the maximum of {} that can be represented", actual, max))
+            .with_io_kind(io::ErrorKind::InvalidInput)
+            .with_actual_length(actual)
+            .with_max_length(max)
+            .into());
+    }
+
+    return Ok(());
+}
+
+///
+/// Trait to attach context to the error of a result.
+/// Implemented for io::Result and Result with Error.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait ResultExt : Sized + private::Sealed {
+
+    ///
+    /// Pushes the name of the field that was being read or written when the error occurred.
+    /// Intended to be called by FromRead/ToWrite implementations for each of their fields.
+    ///
+    fn field(self, name: &'static str) -> Self;
+
+    ///
+    /// Sets the offset at which the error occurred unless an offset is already set.
+    ///
+    fn at_offset(self, offset: u64) -> Self;
+}
+
+impl <T> ResultExt for io::Result<T> {
+    fn field(self, name: &'static str) -> Self {
+        return self.map_err(|err| Error::from(err).with_field(name).into());
+    }
+
+    fn at_offset(self, offset: u64) -> Self {
+        return self.map_err(|err| Error::from(err).with_offset(offset).into());
+    }
+}
+
+impl <T> ResultExt for Result<T, Error> {
+    fn field(self, name: &'static str) -> Self {
+        return self.map_err(|err| err.with_field(name));
+    }
+
+    fn at_offset(self, offset: u64) -> Self {
+        return self.map_err(|err| err.with_offset(offset));
+    }
+}
+
+mod private {
+    use std::io;
+    use crate::error::Error;
+
+    impl <T> Sealed for io::Result<T> {}
+    impl <T> Sealed for Result<T, Error> {}
+    pub trait Sealed {
+
+    }
+}
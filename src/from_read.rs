@@ -1,5 +1,7 @@
 use std::io;
 use std::io::{Cursor, Read};
+use crate::eof::try_read;
+use crate::read_iter::ReadIter;
 
 ///
 /// This trait can be used by all structs that can be serialized to a Read.
@@ -12,43 +14,182 @@ use std::io::{Cursor, Read};
 ///
 pub trait FromRead {
     fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()>;
+
+    ///
+    /// Generic variant of copy_from_read used by ReadFrom, DefaultFromRead and RecordRead.
+    /// Calls copy_from_read by default, implementations of FromReader call copy_from_reader instead.
+    ///
+    fn copy_from_read_generic<R: Read + ?Sized>(&mut self, mut reader: &mut R) -> io::Result<()> where Self: Sized {
+        return self.copy_from_read(&mut reader);
+    }
+}
+
+///
+/// Generic variant of FromRead for performance sensitive types.
+///
+/// copy_from_read calls every method of the reader through a vtable, which prevents inlining small reads
+/// when reading from a Cursor or a slice. Implementing this trait instead allows the compiler to generate
+/// a specialized copy for each reader type. FromRead is automatically implemented for all implementations,
+/// so the type can still be used with DefaultFromRead and everything else that requires FromRead.
+/// ReadFrom, DefaultFromRead and RecordRead call copy_from_reader with the concrete reader type.
+///
+pub trait FromReader {
+    fn copy_from_reader<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<()>;
 }
 
+impl <T> FromRead for T where T: FromReader {
+    fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()> {
+        return self.copy_from_reader(reader);
+    }
+
+    fn copy_from_read_generic<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<()> {
+        return self.copy_from_reader(reader);
+    }
+}
+
+///
+/// Constructor style alternative to FromRead for types that cannot provide a Default value
+/// or that have invariants which must hold for every instance.
 ///
-/// Auto implemented trait to add constructors to all structs that provide a Default impl.
+/// Automatically implemented for all types that implement FromRead and Default.
+/// Option of a ReadFrom type implements FromRead, which allows using ReadFrom types where FromRead is required.
+/// DefaultFromRead is implemented for all ReadFrom types.
+///
+pub trait ReadFrom : Sized {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self>;
+}
+
+impl <T> ReadFrom for T where T: FromRead + Default {
+    fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut x = Self::default();
+        x.copy_from_read_generic(reader)?;
+        return Ok(x);
+    }
+}
+
+impl <T> FromRead for Option<T> where T: ReadFrom {
+    ///
+    /// Reads the value with ReadFrom and replaces self with Some of it.
+    /// Self is left unchanged if reading fails.
+    ///
+    fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()> {
+        *self = Some(T::read_from(reader)?);
+        return Ok(());
+    }
+
+    fn copy_from_read_generic<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<()> {
+        *self = Some(T::read_from(reader)?);
+        return Ok(());
+    }
+}
+
+///
+/// Auto implemented trait to add constructors to all ReadFrom types, which includes all FromRead types that provide a Default impl.
 /// This trait is sealed and cannot be implemented manually.
 ///
 pub trait DefaultFromRead : Sized + private::Sealed {
     fn from_io(read: &mut dyn Read) -> io::Result<Self>;
 
+    ///
+    /// Same as from_io except that None is returned if the reader is at EOF before the first byte.
+    /// This allows reading records until the end of a stream while still detecting a truncated last record.
+    ///
+    fn try_from_io(read: &mut dyn Read) -> io::Result<Option<Self>>;
+
     fn from_vec(vec: &Vec<u8>) -> io::Result<Self>;
 
+    ///
+    /// Same as from_vec except that it fails with a TrailingData error if not all bytes of the vec were read.
+    ///
+    fn from_vec_exact(vec: &Vec<u8>) -> io::Result<Self>;
+
     fn from_slice(slice: &[u8]) -> io::Result<Self>;
+
+    ///
+    /// Same as from_slice except that it fails if not all bytes of the slice were read.
+    /// The error has the kind TrailingData, its offset is the amount of bytes that were read
+    /// and trailing_bytes the amount of bytes that were not read.
+    ///
+    fn from_slice_exact(slice: &[u8]) -> io::Result<Self>;
+
+    ///
+    /// Same as from_slice but also returns the remainder of the slice that was not read.
+    ///
+    fn from_slice_partial(slice: &[u8]) -> io::Result<(Self, &[u8])>;
 }
 
-impl <T> DefaultFromRead for T where T: FromRead + Default {
+impl <T> DefaultFromRead for T where T: ReadFrom {
     fn from_io(read: &mut dyn Read) -> io::Result<Self> {
-        let mut x = Self::default();
-        x.copy_from_read(read)?;
-        return Ok(x);
+        return Self::read_from(read);
+    }
+    fn try_from_io(read: &mut dyn Read) -> io::Result<Option<Self>> {
+        return try_read(read, |reader| Self::from_io(reader));
     }
     fn from_vec(vec: &Vec<u8>) -> io::Result<Self> {
         let mut x:Cursor<&[u8]> = Cursor::new(vec.as_ref());
-        return Self::from_io(&mut x);
+        return Self::read_from(&mut x);
+    }
+    fn from_vec_exact(vec: &Vec<u8>) -> io::Result<Self> {
+        return Self::from_slice_exact(vec.as_slice());
     }
     fn from_slice(slice: &[u8]) -> io::Result<Self> {
         let mut x:Cursor<&[u8]> = Cursor::new(slice.as_ref());
-        return Self::from_io(&mut x);
+        return Self::read_from(&mut x);
+    }
+    fn from_slice_exact(slice: &[u8]) -> io::Result<Self> {
+        let (x, remainder) = Self::from_slice_partial(slice)?;
+        if !remainder.is_empty() {
+            let consumed = slice.len() - remainder.len();
+            return Err(crate::error::Error::new(crate::error::ErrorKind::TrailingData,
+                format!("{} trailing bytes after reading {} of {} bytes", remainder.len(), consumed, slice.len()))
+                .with_offset(consumed as u64)
+                .with_trailing_bytes(remainder.len() as u64)
+                .into());
+        }
+
+        return Ok(x);
+    }
+    fn from_slice_partial(slice: &[u8]) -> io::Result<(Self, &[u8])> {
+        let mut x:Cursor<&[u8]> = Cursor::new(slice);
+        let result = Self::read_from(&mut x)?;
+        let consumed = x.position() as usize;
+        return Ok((result, &slice[consumed..]));
+    }
+}
+
+///
+/// Trait that provides an iterator over consecutive records of a FromRead type.
+/// Automatically implemented for all implementations of io::Read.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait RecordRead : private::ReadSealed {
+
+    ///
+    /// Returns an iterator that reads records with ReadFrom until the reader is at EOF.
+    /// A record that is cut off by the end of the data is yielded as UnexpectedEof error.
+    ///
+    fn iter_records<T: ReadFrom>(&mut self) -> ReadIter<'_, Self, T> where Self: Sized;
+}
+
+impl <R> RecordRead for R where R: Read {
+    fn iter_records<T: ReadFrom>(&mut self) -> ReadIter<'_, Self, T> {
+        return ReadIter::new(self, |reader| try_read(reader, |reader| T::read_from(reader)));
     }
 }
 
 mod private {
-    use crate::from_read::FromRead;
+    use std::io::Read;
+    use crate::from_read::ReadFrom;
 
-    impl <T> Sealed for T where T: FromRead + Default {}
+    impl <T> Sealed for T where T: ReadFrom {}
     pub trait Sealed {
 
     }
+
+    impl <T> ReadSealed for T where T: Read {}
+    pub trait ReadSealed {
+
+    }
 }
 
 
@@ -0,0 +1,189 @@
+use std::io;
+use std::io::{BufRead, ErrorKind, Read};
+use crate::num_read::NumRead;
+use crate::string_read::StringRead;
+
+///
+/// Trait that provides the methods of java.io.DataInput with the exact same semantics as
+/// java.io.DataInputStream. All values are read in big endian byte order.
+/// Automatically implemented for all implementations of io::Read.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait JavaDataInput : private::Sealed {
+
+    ///
+    /// java.io.DataInput#readBoolean
+    /// Reads one byte and treats 0 as false and any other value as true.
+    ///
+    fn read_java_boolean(&mut self) -> io::Result<bool>;
+
+    ///
+    /// java.io.DataInput#readByte
+    ///
+    fn read_java_byte(&mut self) -> io::Result<i8>;
+
+    ///
+    /// java.io.DataInput#readUnsignedByte
+    ///
+    fn read_java_unsigned_byte(&mut self) -> io::Result<u8>;
+
+    ///
+    /// java.io.DataInput#readShort
+    ///
+    fn read_java_short(&mut self) -> io::Result<i16>;
+
+    ///
+    /// java.io.DataInput#readUnsignedShort
+    ///
+    fn read_java_unsigned_short(&mut self) -> io::Result<u16>;
+
+    ///
+    /// java.io.DataInput#readChar
+    /// A java char is a single utf-16 code unit and may be half of a surrogate pair,
+    /// it is therefore returned as u16.
+    ///
+    fn read_java_char(&mut self) -> io::Result<u16>;
+
+    ///
+    /// java.io.DataInput#readInt
+    ///
+    fn read_java_int(&mut self) -> io::Result<i32>;
+
+    ///
+    /// java.io.DataInput#readLong
+    ///
+    fn read_java_long(&mut self) -> io::Result<i64>;
+
+    ///
+    /// java.io.DataInput#readFloat
+    ///
+    fn read_java_float(&mut self) -> io::Result<f32>;
+
+    ///
+    /// java.io.DataInput#readDouble
+    ///
+    fn read_java_double(&mut self) -> io::Result<f64>;
+
+    ///
+    /// java.io.DataInput#readUTF
+    ///
+    fn read_java_utf(&mut self) -> io::Result<String>;
+
+    ///
+    /// java.io.DataInput#skipBytes
+    /// Skips up to n bytes. Returns the amount of bytes skipped, which is less than n if the end of the stream is reached.
+    ///
+    fn skip_java_bytes(&mut self, n: usize) -> io::Result<usize>;
+
+    ///
+    /// java.io.DataInputStream#readLine
+    /// Reads bytes until '\n', '\r' or "\r\n" and converts each byte to the char with the same value.
+    /// The line terminator is not part of the returned string.
+    /// Returns None if the end of the stream is reached before any byte was read.
+    /// This requires a BufRead as java needs to push back the byte following a '\r'.
+    ///
+    fn read_java_line(&mut self) -> io::Result<Option<String>> where Self: BufRead;
+}
+
+impl <T> JavaDataInput for T where T: Read {
+    fn read_java_boolean(&mut self) -> io::Result<bool> {
+        return self.read_bool();
+    }
+
+    fn read_java_byte(&mut self) -> io::Result<i8> {
+        return self.read_i8();
+    }
+
+    fn read_java_unsigned_byte(&mut self) -> io::Result<u8> {
+        return self.read_u8();
+    }
+
+    fn read_java_short(&mut self) -> io::Result<i16> {
+        return self.read_i16_be();
+    }
+
+    fn read_java_unsigned_short(&mut self) -> io::Result<u16> {
+        return self.read_u16_be();
+    }
+
+    fn read_java_char(&mut self) -> io::Result<u16> {
+        return self.read_u16_be();
+    }
+
+    fn read_java_int(&mut self) -> io::Result<i32> {
+        return self.read_i32_be();
+    }
+
+    fn read_java_long(&mut self) -> io::Result<i64> {
+        return self.read_i64_be();
+    }
+
+    fn read_java_float(&mut self) -> io::Result<f32> {
+        return self.read_f32_be();
+    }
+
+    fn read_java_double(&mut self) -> io::Result<f64> {
+        return self.read_f64_be();
+    }
+
+    fn read_java_utf(&mut self) -> io::Result<String> {
+        return self.read_java_data_input_utf();
+    }
+
+    fn skip_java_bytes(&mut self, n: usize) -> io::Result<usize> {
+        let skipped = io::copy(&mut self.take(n as u64), &mut io::sink())?;
+        return Ok(skipped as usize);
+    }
+
+    fn read_java_line(&mut self) -> io::Result<Option<String>> where Self: BufRead {
+        let mut line = String::new();
+        let mut any = false;
+        loop {
+            let available = match self.fill_buf() {
+                Ok(available) => available,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e)
+            };
+
+            if available.is_empty() {
+                if !any {
+                    return Ok(None);
+                }
+
+                return Ok(Some(line));
+            }
+
+            any = true;
+            let c = available[0];
+            self.consume(1);
+            match c {
+                b'\n' => return Ok(Some(line)),
+                b'\r' => {
+                    let next = loop {
+                        match self.fill_buf() {
+                            Ok(available) => break available.first().copied(),
+                            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                            Err(e) => return Err(e)
+                        }
+                    };
+
+                    if next == Some(b'\n') {
+                        self.consume(1);
+                    }
+
+                    return Ok(Some(line));
+                }
+                _ => line.push(c as char)
+            }
+        }
+    }
+}
+
+mod private {
+    use std::io::Read;
+
+    impl <T> Sealed for T where T: Read {}
+    pub trait Sealed {
+
+    }
+}
@@ -0,0 +1,161 @@
+use std::io;
+use std::io::Write;
+use crate::num_write::NumWrite;
+use crate::string_write::StringWrite;
+
+///
+/// Trait that provides the methods of java.io.DataOutput with the exact same semantics as
+/// java.io.DataOutputStream. All values are written in big endian byte order.
+/// Automatically implemented for all implementations of io::Write.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait JavaDataOutput : private::Sealed {
+
+    ///
+    /// java.io.DataOutput#writeBoolean
+    /// Writes 1 for true and 0 for false.
+    ///
+    fn write_java_boolean(&mut self, value: bool) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeByte
+    ///
+    fn write_java_byte(&mut self, value: i8) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeShort
+    ///
+    fn write_java_short(&mut self, value: i16) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeChar
+    /// A java char is a single utf-16 code unit and may be half of a surrogate pair,
+    /// it is therefore passed as u16.
+    ///
+    fn write_java_char(&mut self, value: u16) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeInt
+    ///
+    fn write_java_int(&mut self, value: i32) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeLong
+    ///
+    fn write_java_long(&mut self, value: i64) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeFloat
+    /// The value is converted with float_to_int_bits, so all NaN values are written as the canonical NaN.
+    ///
+    fn write_java_float(&mut self, value: f32) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeDouble
+    /// The value is converted with double_to_long_bits, so all NaN values are written as the canonical NaN.
+    ///
+    fn write_java_double(&mut self, value: f64) -> io::Result<()>;
+
+    ///
+    /// java.io.DataOutput#writeBytes
+    /// Writes the low 8 bits of each utf-16 code unit of the string, the high 8 bits are discarded.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_bytes(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// java.io.DataOutput#writeChars
+    /// Writes each utf-16 code unit of the string as 2 bytes in big endian.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_chars(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// java.io.DataOutput#writeUTF
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_utf(&mut self, string: &str) -> io::Result<usize>;
+}
+
+///
+/// java.lang.Float#floatToIntBits
+/// Same as f32::to_bits except that all NaN values are collapsed to the canonical NaN 0x7fc00000.
+///
+pub fn float_to_int_bits(value: f32) -> i32 {
+    if value.is_nan() {
+        return 0x7fc00000;
+    }
+
+    return value.to_bits() as i32;
+}
+
+///
+/// java.lang.Double#doubleToLongBits
+/// Same as f64::to_bits except that all NaN values are collapsed to the canonical NaN 0x7ff8000000000000.
+///
+pub fn double_to_long_bits(value: f64) -> i64 {
+    if value.is_nan() {
+        return 0x7ff8000000000000;
+    }
+
+    return value.to_bits() as i64;
+}
+
+impl <T> JavaDataOutput for T where T: Write {
+    fn write_java_boolean(&mut self, value: bool) -> io::Result<()> {
+        return self.write_bool(value);
+    }
+
+    fn write_java_byte(&mut self, value: i8) -> io::Result<()> {
+        return self.write_i8(value);
+    }
+
+    fn write_java_short(&mut self, value: i16) -> io::Result<()> {
+        return self.write_i16_be(value);
+    }
+
+    fn write_java_char(&mut self, value: u16) -> io::Result<()> {
+        return self.write_u16_be(value);
+    }
+
+    fn write_java_int(&mut self, value: i32) -> io::Result<()> {
+        return self.write_i32_be(value);
+    }
+
+    fn write_java_long(&mut self, value: i64) -> io::Result<()> {
+        return self.write_i64_be(value);
+    }
+
+    fn write_java_float(&mut self, value: f32) -> io::Result<()> {
+        return self.write_i32_be(float_to_int_bits(value));
+    }
+
+    fn write_java_double(&mut self, value: f64) -> io::Result<()> {
+        return self.write_i64_be(double_to_long_bits(value));
+    }
+
+    fn write_java_bytes(&mut self, string: &str) -> io::Result<usize> {
+        let data: Vec<u8> = string.encode_utf16().map(|c| c as u8).collect();
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
+
+    fn write_java_chars(&mut self, string: &str) -> io::Result<usize> {
+        let data: Vec<u8> = string.encode_utf16().flat_map(|c| c.to_be_bytes()).collect();
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
+
+    fn write_java_utf(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_java_data_output_utf(string);
+    }
+}
+
+mod private {
+    use std::io::Write;
+
+    impl <T> Sealed for T where T: Write {}
+    pub trait Sealed {
+
+    }
+}
@@ -0,0 +1,881 @@
+use std::io;
+use std::io::{Cursor, Error, ErrorKind, Read, Write};
+use crate::error::ResultExt;
+use crate::from_read::FromRead;
+use crate::java_data_input::JavaDataInput;
+use crate::java_data_output::JavaDataOutput;
+use crate::num_read::NumRead;
+use crate::num_write::NumWrite;
+use crate::string_read::StringRead;
+use crate::counting::{CountingReader, Position};
+use crate::error::check_length;
+use crate::string_write::StringWrite;
+use crate::to_write::ToWrite;
+
+pub const STREAM_MAGIC: u16 = 0xACED;
+pub const STREAM_VERSION: u16 = 5;
+
+pub const TC_NULL: u8 = 0x70;
+pub const TC_REFERENCE: u8 = 0x71;
+pub const TC_CLASSDESC: u8 = 0x72;
+pub const TC_OBJECT: u8 = 0x73;
+pub const TC_STRING: u8 = 0x74;
+pub const TC_ARRAY: u8 = 0x75;
+pub const TC_CLASS: u8 = 0x76;
+pub const TC_BLOCKDATA: u8 = 0x77;
+pub const TC_ENDBLOCKDATA: u8 = 0x78;
+pub const TC_RESET: u8 = 0x79;
+pub const TC_BLOCKDATALONG: u8 = 0x7A;
+pub const TC_EXCEPTION: u8 = 0x7B;
+pub const TC_LONGSTRING: u8 = 0x7C;
+pub const TC_PROXYCLASSDESC: u8 = 0x7D;
+pub const TC_ENUM: u8 = 0x7E;
+
+///
+/// The first handle that is assigned in a stream. References refer to handles relative to this value.
+///
+pub const BASE_WIRE_HANDLE: u32 = 0x7E0000;
+
+pub const SC_WRITE_METHOD: u8 = 0x01;
+pub const SC_SERIALIZABLE: u8 = 0x02;
+pub const SC_EXTERNALIZABLE: u8 = 0x04;
+pub const SC_BLOCK_DATA: u8 = 0x08;
+pub const SC_ENUM: u8 = 0x10;
+
+///
+/// Maximum nesting depth of objects, class descriptors and arrays when reading a stream.
+/// Deeper streams are rejected with InvalidData instead of overflowing the stack,
+/// similar to the maxdepth limit of java's serialization filters.
+///
+pub const MAX_DEPTH: usize = 256;
+
+///
+/// A stream produced by java.io.ObjectOutputStream and readable by java.io.ObjectInputStream.
+///
+/// The stream is represented as a tree of its contents. Back references are not resolved but kept
+/// as JavaObject::Reference, which makes writing the tree produce the exact same bytes it was read from.
+///
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct JavaSerializationStream {
+    pub contents: Vec<Content>,
+}
+
+///
+/// Top level element of a stream or element of the annotations written by a writeObject or writeExternal method.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    ///
+    /// An object written by writeObject.
+    ///
+    Object(JavaObject),
+
+    ///
+    /// Primitive data written by the write methods of java.io.DataOutput.
+    ///
+    BlockData(Vec<u8>),
+
+    ///
+    /// A reset of the handle table caused by ObjectOutputStream#reset.
+    ///
+    Reset,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JavaObject {
+    Null,
+
+    ///
+    /// Reference to an object that was previously written to the stream.
+    /// The value is the handle including BASE_WIRE_HANDLE.
+    ///
+    Reference(u32),
+    ClassDesc(Box<ClassDesc>),
+    ProxyClassDesc(Box<ProxyClassDesc>),
+
+    ///
+    /// A string, TC_LONGSTRING is used when writing if the encoded string is longer than 65535 bytes.
+    ///
+    String(String),
+    Object(Box<ObjectData>),
+    Array(Box<ArrayData>),
+    Enum(Box<EnumData>),
+
+    ///
+    /// A java.lang.Class object, contains the class descriptor.
+    ///
+    Class(Box<JavaObject>),
+
+    ///
+    /// The throwable that was thrown while writing the stream.
+    ///
+    Exception(Box<JavaObject>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDesc {
+    pub name: String,
+    pub serial_version_uid: i64,
+
+    ///
+    /// Combination of the SC_* constants.
+    ///
+    pub flags: u8,
+    pub fields: Vec<FieldDesc>,
+    pub annotations: Vec<Content>,
+
+    ///
+    /// The class descriptor of the super class. JavaObject::Null if there is no serializable super class.
+    ///
+    pub super_class: JavaObject,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxyClassDesc {
+    pub interfaces: Vec<String>,
+    pub annotations: Vec<Content>,
+    pub super_class: JavaObject,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDesc {
+    ///
+    /// One of B, C, D, F, I, J, S, Z for primitives, L for objects and [ for arrays.
+    ///
+    pub type_code: u8,
+    pub name: String,
+
+    ///
+    /// The field type in jvm descriptor format, only present for object and array fields.
+    ///
+    pub class_name: Option<JavaObject>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectData {
+    pub class_desc: JavaObject,
+
+    ///
+    /// The data of each serializable class in the hierarchy, starting with the top most super class.
+    ///
+    pub class_data: Vec<ClassData>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassData {
+    ///
+    /// The values of all fields in the order of the class descriptor.
+    ///
+    pub values: Vec<Value>,
+
+    ///
+    /// Data written by a writeObject or writeExternal method.
+    /// Present if the class descriptor has the SC_WRITE_METHOD or SC_BLOCK_DATA flag.
+    ///
+    pub annotations: Option<Vec<Content>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Byte(i8),
+    Char(u16),
+    Double(f64),
+    Float(f32),
+    Int(i32),
+    Long(i64),
+    Short(i16),
+    Boolean(bool),
+    Object(JavaObject),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArrayData {
+    pub class_desc: JavaObject,
+    pub values: ArrayValues,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArrayValues {
+    Byte(Vec<i8>),
+    Char(Vec<u16>),
+    Double(Vec<f64>),
+    Float(Vec<f32>),
+    Int(Vec<i32>),
+    Long(Vec<i64>),
+    Short(Vec<i16>),
+    Boolean(Vec<bool>),
+    Object(Vec<JavaObject>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumData {
+    pub class_desc: JavaObject,
+
+    ///
+    /// The name of the enum constant, a JavaObject::String or a JavaObject::Reference to a string.
+    ///
+    pub constant_name: JavaObject,
+}
+
+impl JavaSerializationStream {
+
+    ///
+    /// Returns the handle table as it is at the end of the stream.
+    /// Index 0 corresponds to the handle BASE_WIRE_HANDLE.
+    ///
+    pub fn handles(&self) -> Vec<&JavaObject> {
+        let mut handles = Vec::new();
+        for content in self.contents.iter() {
+            collect_content_handles(content, &mut handles);
+        }
+
+        return handles;
+    }
+
+    ///
+    /// Resolves a handle as found in JavaObject::Reference using the handle table at the end of the stream.
+    ///
+    pub fn resolve(&self, handle: u32) -> Option<&JavaObject> {
+        let index = handle.checked_sub(BASE_WIRE_HANDLE)? as usize;
+        return self.handles().get(index).copied();
+    }
+}
+
+fn collect_content_handles<'a>(content: &'a Content, handles: &mut Vec<&'a JavaObject>) {
+    match content {
+        Content::Object(object) => collect_handles(object, handles),
+        Content::BlockData(_) => {}
+        Content::Reset => handles.clear(),
+    }
+}
+
+fn collect_handles<'a>(object: &'a JavaObject, handles: &mut Vec<&'a JavaObject>) {
+    match object {
+        JavaObject::Null | JavaObject::Reference(_) => {}
+        JavaObject::ClassDesc(desc) => {
+            handles.push(object);
+            for field in desc.fields.iter() {
+                if let Some(class_name) = &field.class_name {
+                    collect_handles(class_name, handles);
+                }
+            }
+            desc.annotations.iter().for_each(|c| collect_content_handles(c, handles));
+            collect_handles(&desc.super_class, handles);
+        }
+        JavaObject::ProxyClassDesc(desc) => {
+            handles.push(object);
+            desc.annotations.iter().for_each(|c| collect_content_handles(c, handles));
+            collect_handles(&desc.super_class, handles);
+        }
+        JavaObject::String(_) => handles.push(object),
+        JavaObject::Object(data) => {
+            collect_handles(&data.class_desc, handles);
+            handles.push(object);
+            for class_data in data.class_data.iter() {
+                for value in class_data.values.iter() {
+                    if let Value::Object(value) = value {
+                        collect_handles(value, handles);
+                    }
+                }
+
+                if let Some(annotations) = &class_data.annotations {
+                    annotations.iter().for_each(|c| collect_content_handles(c, handles));
+                }
+            }
+        }
+        JavaObject::Array(data) => {
+            collect_handles(&data.class_desc, handles);
+            handles.push(object);
+            if let ArrayValues::Object(values) = &data.values {
+                values.iter().for_each(|v| collect_handles(v, handles));
+            }
+        }
+        JavaObject::Enum(data) => {
+            collect_handles(&data.class_desc, handles);
+            handles.push(object);
+            collect_handles(&data.constant_name, handles);
+        }
+        JavaObject::Class(desc) => {
+            collect_handles(desc, handles);
+            handles.push(object);
+        }
+        JavaObject::Exception(throwable) => {
+            handles.clear();
+            collect_handles(throwable, handles);
+            handles.clear();
+        }
+    }
+}
+
+fn invalid(msg: &str) -> Error {
+    return Error::new(ErrorKind::InvalidData, msg.to_string());
+}
+
+///
+/// The information about a class descriptor that is needed to parse the data of objects.
+///
+#[derive(Clone)]
+struct ClassInfo {
+    name: String,
+    flags: u8,
+    fields: Vec<u8>,
+    super_class: Option<usize>,
+}
+
+struct StreamReader<'a> {
+    reader: &'a mut dyn Read,
+    handles: Vec<Option<ClassInfo>>,
+    depth: usize,
+}
+
+impl <'a> StreamReader<'a> {
+
+    ///
+    /// Increments the nesting depth, must be paired with a call to leave.
+    ///
+    fn enter(&mut self) -> io::Result<()> {
+        if self.depth >= MAX_DEPTH {
+            return Err(crate::error::Error::new(crate::error::ErrorKind::LimitExceeded,
+                format!("stream exceeds maximum nesting depth of {}", MAX_DEPTH))
+                .with_limit(MAX_DEPTH as u64)
+                .into());
+        }
+
+        self.depth += 1;
+        return Ok(());
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn new_handle(&mut self) -> usize {
+        self.handles.push(None);
+        return self.handles.len() - 1;
+    }
+
+    fn read_modified_utf8(&mut self, size: u64) -> io::Result<String> {
+        let mut data = Vec::new();
+        self.reader.take(size).read_to_end(&mut data)?;
+        if (data.len() as u64) != size {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        return Cursor::new(data.as_slice()).read_java_modified_utf8(data.len());
+    }
+
+    fn read_handle(&mut self) -> io::Result<u32> {
+        let handle = self.reader.read_java_int()? as u32;
+        let index = handle.wrapping_sub(BASE_WIRE_HANDLE) as usize;
+        if handle < BASE_WIRE_HANDLE || index >= self.handles.len() {
+            return Err(invalid("reference to unknown handle"));
+        }
+
+        return Ok(handle);
+    }
+
+    fn read_contents_until_end(&mut self) -> io::Result<Vec<Content>> {
+        let mut contents = Vec::new();
+        loop {
+            let tc = self.reader.read_u8()?;
+            if tc == TC_ENDBLOCKDATA {
+                return Ok(contents);
+            }
+
+            contents.push(self.read_content(tc)?);
+        }
+    }
+
+    fn read_content(&mut self, tc: u8) -> io::Result<Content> {
+        let size = match tc {
+            TC_BLOCKDATA => self.reader.read_u8()? as u64,
+            TC_BLOCKDATALONG => {
+                let size = self.reader.read_java_int()?;
+                if size < 0 {
+                    return Err(invalid("negative block data length"));
+                }
+                size as u64
+            }
+            TC_RESET => {
+                self.handles.clear();
+                return Ok(Content::Reset);
+            }
+            _ => return Ok(Content::Object(self.read_object_tc(tc)?))
+        };
+
+        let mut data = Vec::new();
+        self.reader.take(size).read_to_end(&mut data)?;
+        if (data.len() as u64) != size {
+            return Err(Error::new(ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+        }
+
+        return Ok(Content::BlockData(data));
+    }
+
+    fn read_object(&mut self) -> io::Result<JavaObject> {
+        let tc = self.reader.read_u8()?;
+        return self.read_object_tc(tc);
+    }
+
+    fn read_object_tc(&mut self, tc: u8) -> io::Result<JavaObject> {
+        self.enter()?;
+        let object = self.read_object_tc_nested(tc);
+        self.leave();
+        return object;
+    }
+
+    fn read_object_tc_nested(&mut self, tc: u8) -> io::Result<JavaObject> {
+        return match tc {
+            TC_NULL => Ok(JavaObject::Null),
+            TC_REFERENCE => Ok(JavaObject::Reference(self.read_handle()?)),
+            TC_CLASSDESC | TC_PROXYCLASSDESC => Ok(self.read_class_desc_tc(tc)?.0),
+            TC_STRING => {
+                self.new_handle();
+                let size = self.reader.read_java_unsigned_short()?;
+                Ok(JavaObject::String(self.read_modified_utf8(size as u64)?))
+            }
+            TC_LONGSTRING => {
+                self.new_handle();
+                let size = self.reader.read_java_long()?;
+                Ok(JavaObject::String(self.read_modified_utf8(size as u64)?))
+            }
+            TC_OBJECT => self.read_new_object(),
+            TC_ARRAY => self.read_new_array(),
+            TC_ENUM => {
+                let (class_desc, _) = self.read_class_desc().field("class_desc")?;
+                self.new_handle();
+                let constant_name = self.read_object().field("constant_name")?;
+                Ok(JavaObject::Enum(Box::new(EnumData { class_desc, constant_name })))
+            }
+            TC_CLASS => {
+                let (class_desc, _) = self.read_class_desc().field("class_desc")?;
+                self.new_handle();
+                Ok(JavaObject::Class(Box::new(class_desc)))
+            }
+            TC_EXCEPTION => {
+                self.handles.clear();
+                let throwable = self.read_object().field("throwable")?;
+                self.handles.clear();
+                Ok(JavaObject::Exception(Box::new(throwable)))
+            }
+            TC_RESET => Err(Error::new(ErrorKind::Unsupported, "TC_RESET is only supported between top level objects")),
+            _ => Err(invalid("invalid type code"))
+        };
+    }
+
+    ///
+    /// Reads a class descriptor and returns it together with its index in the handle table.
+    ///
+    fn read_class_desc(&mut self) -> io::Result<(JavaObject, Option<usize>)> {
+        let tc = self.reader.read_u8()?;
+        return self.read_class_desc_tc(tc);
+    }
+
+    fn read_class_desc_tc(&mut self, tc: u8) -> io::Result<(JavaObject, Option<usize>)> {
+        self.enter()?;
+        let desc = self.read_class_desc_tc_nested(tc);
+        self.leave();
+        return desc;
+    }
+
+    fn read_class_desc_tc_nested(&mut self, tc: u8) -> io::Result<(JavaObject, Option<usize>)> {
+        match tc {
+            TC_NULL => return Ok((JavaObject::Null, None)),
+            TC_REFERENCE => {
+                let handle = self.read_handle()?;
+                let index = (handle - BASE_WIRE_HANDLE) as usize;
+                if self.handles[index].is_none() {
+                    return Err(invalid("reference to a handle that is not a class descriptor"));
+                }
+                return Ok((JavaObject::Reference(handle), Some(index)));
+            }
+            TC_PROXYCLASSDESC => {
+                let index = self.new_handle();
+                let count = self.reader.read_java_int()?;
+                if count < 0 {
+                    return Err(invalid("negative interface count"));
+                }
+
+                let mut interfaces = Vec::new();
+                for _ in 0 .. count {
+                    interfaces.push(self.reader.read_java_utf().field("interfaces")?);
+                }
+
+                let annotations = self.read_contents_until_end().field("annotations")?;
+                let (super_class, super_index) = self.read_class_desc().field("super_class")?;
+                self.handles[index] = Some(ClassInfo { name: String::new(), flags: SC_SERIALIZABLE, fields: Vec::new(), super_class: super_index });
+                let desc = ProxyClassDesc { interfaces, annotations, super_class };
+                return Ok((JavaObject::ProxyClassDesc(Box::new(desc)), Some(index)));
+            }
+            TC_CLASSDESC => {}
+            _ => return Err(invalid("invalid type code for class descriptor"))
+        }
+
+        let name = self.reader.read_java_utf()?;
+        let serial_version_uid = self.reader.read_java_long()?;
+        let index = self.new_handle();
+        let flags = self.reader.read_u8()?;
+        let count = self.reader.read_java_short()?;
+        if count < 0 {
+            return Err(invalid("negative field count"));
+        }
+
+        let mut fields = Vec::new();
+        for _ in 0 .. count {
+            fields.push(self.read_field_desc().field("fields")?);
+        }
+
+        let annotations = self.read_contents_until_end().field("annotations")?;
+        let (super_class, super_index) = self.read_class_desc().field("super_class")?;
+        self.handles[index] = Some(ClassInfo {
+            name: name.clone(),
+            flags,
+            fields: fields.iter().map(|f| f.type_code).collect(),
+            super_class: super_index,
+        });
+
+        let desc = ClassDesc { name, serial_version_uid, flags, fields, annotations, super_class };
+        return Ok((JavaObject::ClassDesc(Box::new(desc)), Some(index)));
+    }
+
+    fn read_field_desc(&mut self) -> io::Result<FieldDesc> {
+        let type_code = self.reader.read_u8()?;
+        let name = self.reader.read_java_utf()?;
+        let class_name = match type_code {
+            b'B' | b'C' | b'D' | b'F' | b'I' | b'J' | b'S' | b'Z' => None,
+            b'L' | b'[' => Some(self.read_object().field("class_name")?),
+            _ => return Err(invalid("invalid field type code"))
+        };
+
+        return Ok(FieldDesc { type_code, name, class_name });
+    }
+
+    fn class_info(&self, index: Option<usize>) -> io::Result<ClassInfo> {
+        return index.and_then(|i| self.handles.get(i).cloned().flatten())
+            .ok_or_else(|| invalid("missing class descriptor"));
+    }
+
+    fn read_new_object(&mut self) -> io::Result<JavaObject> {
+        let (class_desc, index) = self.read_class_desc().field("class_desc")?;
+        self.new_handle();
+
+        let mut hierarchy = Vec::new();
+        let mut cur = index;
+        while cur.is_some() {
+            let info = self.class_info(cur)?;
+            cur = info.super_class;
+            hierarchy.push(info);
+            if hierarchy.len() > self.handles.len() {
+                return Err(invalid("cyclic class hierarchy"));
+            }
+        }
+
+        let mut class_data = Vec::new();
+        for info in hierarchy.iter().rev() {
+            let mut values = Vec::new();
+            let mut annotations = None;
+            if info.flags & SC_EXTERNALIZABLE != 0 {
+                if info.flags & SC_BLOCK_DATA == 0 {
+                    return Err(Error::new(ErrorKind::Unsupported, "externalizable objects written with protocol version 1 are not supported"));
+                }
+                annotations = Some(self.read_contents_until_end().field("annotations").field("class_data")?);
+            } else {
+                for type_code in info.fields.iter() {
+                    values.push(self.read_value(*type_code).field("values").field("class_data")?);
+                }
+
+                if info.flags & SC_WRITE_METHOD != 0 {
+                    annotations = Some(self.read_contents_until_end().field("annotations").field("class_data")?);
+                }
+            }
+
+            class_data.push(ClassData { values, annotations });
+        }
+
+        return Ok(JavaObject::Object(Box::new(ObjectData { class_desc, class_data })));
+    }
+
+    fn read_value(&mut self, type_code: u8) -> io::Result<Value> {
+        return Ok(match type_code {
+            b'B' => Value::Byte(self.reader.read_java_byte()?),
+            b'C' => Value::Char(self.reader.read_java_char()?),
+            b'D' => Value::Double(self.reader.read_f64_be()?),
+            b'F' => Value::Float(self.reader.read_f32_be()?),
+            b'I' => Value::Int(self.reader.read_java_int()?),
+            b'J' => Value::Long(self.reader.read_java_long()?),
+            b'S' => Value::Short(self.reader.read_java_short()?),
+            b'Z' => Value::Boolean(self.reader.read_java_boolean()?),
+            _ => Value::Object(self.read_object()?),
+        });
+    }
+
+    fn read_new_array(&mut self) -> io::Result<JavaObject> {
+        let (class_desc, index) = self.read_class_desc().field("class_desc")?;
+        let info = self.class_info(index)?;
+        self.new_handle();
+        let size = self.reader.read_java_int()?;
+        if size < 0 {
+            return Err(invalid("negative array length"));
+        }
+
+        let element = match info.name.as_bytes() {
+            [b'[', element, ..] => *element,
+            _ => return Err(invalid("array class name does not start with ["))
+        };
+
+        //The size is not trusted for pre allocation as a malicious stream could cause huge allocations.
+        let size = size as usize;
+        let cap = size.min(1024);
+        let values = self.read_array(element, size, cap).field("values")?;
+        return Ok(JavaObject::Array(Box::new(ArrayData { class_desc, values })));
+    }
+
+    fn read_array(&mut self, element: u8, size: usize, cap: usize) -> io::Result<ArrayValues> {
+        return Ok(match element {
+            b'B' => ArrayValues::Byte(self.read_array_values(size, cap, |r| r.read_java_byte())?),
+            b'C' => ArrayValues::Char(self.read_array_values(size, cap, |r| r.read_java_char())?),
+            b'D' => ArrayValues::Double(self.read_array_values(size, cap, |r| r.read_f64_be())?),
+            b'F' => ArrayValues::Float(self.read_array_values(size, cap, |r| r.read_f32_be())?),
+            b'I' => ArrayValues::Int(self.read_array_values(size, cap, |r| r.read_java_int())?),
+            b'J' => ArrayValues::Long(self.read_array_values(size, cap, |r| r.read_java_long())?),
+            b'S' => ArrayValues::Short(self.read_array_values(size, cap, |r| r.read_java_short())?),
+            b'Z' => ArrayValues::Boolean(self.read_array_values(size, cap, |r| r.read_java_boolean())?),
+            b'L' | b'[' => {
+                let mut values = Vec::with_capacity(cap);
+                for _ in 0 .. size {
+                    values.push(self.read_object()?);
+                }
+                ArrayValues::Object(values)
+            }
+            _ => return Err(invalid("invalid array element type"))
+        });
+    }
+
+    fn read_array_values<T>(&mut self, size: usize, cap: usize, read: fn(&mut &'a mut dyn Read) -> io::Result<T>) -> io::Result<Vec<T>> {
+        let mut values = Vec::with_capacity(cap);
+        for _ in 0 .. size {
+            values.push(read(&mut self.reader)?);
+        }
+
+        return Ok(values);
+    }
+}
+
+struct StreamWriter<'a> {
+    writer: &'a mut dyn Write,
+}
+
+impl <'a> StreamWriter<'a> {
+
+    fn write_contents_with_end(&mut self, contents: &[Content]) -> io::Result<()> {
+        for content in contents {
+            self.write_content(content)?;
+        }
+
+        return self.writer.write_u8(TC_ENDBLOCKDATA);
+    }
+
+    fn write_content(&mut self, content: &Content) -> io::Result<()> {
+        match content {
+            Content::Object(object) => return self.write_object(object),
+            Content::Reset => return self.writer.write_u8(TC_RESET),
+            Content::BlockData(data) => {
+                if data.len() <= 0xFF {
+                    self.writer.write_u8(TC_BLOCKDATA)?;
+                    self.writer.write_u8(data.len() as u8)?;
+                } else {
+                    check_length(data.len() as u64, i32::MAX as u64)?;
+                    self.writer.write_u8(TC_BLOCKDATALONG)?;
+                    self.writer.write_java_int(data.len() as i32)?;
+                }
+
+                return self.writer.write_all(data.as_slice());
+            }
+        }
+    }
+
+    fn write_object(&mut self, object: &JavaObject) -> io::Result<()> {
+        match object {
+            JavaObject::Null => self.writer.write_u8(TC_NULL)?,
+            JavaObject::Reference(handle) => {
+                self.writer.write_u8(TC_REFERENCE)?;
+                self.writer.write_java_int(*handle as i32)?;
+            }
+            JavaObject::ClassDesc(desc) => {
+                self.writer.write_u8(TC_CLASSDESC)?;
+                self.writer.write_java_utf(desc.name.as_str())?;
+                self.writer.write_java_long(desc.serial_version_uid)?;
+                self.writer.write_u8(desc.flags)?;
+                check_length(desc.fields.len() as u64, i16::MAX as u64)?;
+                self.writer.write_java_short(desc.fields.len() as i16)?;
+                for field in desc.fields.iter() {
+                    self.writer.write_u8(field.type_code)?;
+                    self.writer.write_java_utf(field.name.as_str())?;
+                    match (field.type_code, &field.class_name) {
+                        (b'L', Some(class_name)) | (b'[', Some(class_name)) => self.write_object(class_name)?,
+                        (b'L', None) | (b'[', None) => return Err(Error::new(ErrorKind::InvalidInput, "object field without class name")),
+                        _ => {}
+                    }
+                }
+                self.write_contents_with_end(desc.annotations.as_slice())?;
+                self.write_object(&desc.super_class)?;
+            }
+            JavaObject::ProxyClassDesc(desc) => {
+                self.writer.write_u8(TC_PROXYCLASSDESC)?;
+                check_length(desc.interfaces.len() as u64, i32::MAX as u64)?;
+                self.writer.write_java_int(desc.interfaces.len() as i32)?;
+                for interface in desc.interfaces.iter() {
+                    self.writer.write_java_utf(interface.as_str())?;
+                }
+                self.write_contents_with_end(desc.annotations.as_slice())?;
+                self.write_object(&desc.super_class)?;
+            }
+            JavaObject::String(string) => {
+                let mut data = Vec::with_capacity(string.len());
+                data.write_java_modified_utf8(string.as_str())?;
+                if data.len() <= 0xFFFF {
+                    self.writer.write_u8(TC_STRING)?;
+                    self.writer.write_u16_be(data.len() as u16)?;
+                } else {
+                    self.writer.write_u8(TC_LONGSTRING)?;
+                    self.writer.write_java_long(data.len() as i64)?;
+                }
+                self.writer.write_all(data.as_slice())?;
+            }
+            JavaObject::Object(data) => {
+                self.writer.write_u8(TC_OBJECT)?;
+                self.write_object(&data.class_desc)?;
+                for class_data in data.class_data.iter() {
+                    for value in class_data.values.iter() {
+                        self.write_value(value)?;
+                    }
+
+                    if let Some(annotations) = &class_data.annotations {
+                        self.write_contents_with_end(annotations.as_slice())?;
+                    }
+                }
+            }
+            JavaObject::Array(data) => {
+                self.writer.write_u8(TC_ARRAY)?;
+                self.write_object(&data.class_desc)?;
+                self.write_array_values(&data.values)?;
+            }
+            JavaObject::Enum(data) => {
+                self.writer.write_u8(TC_ENUM)?;
+                self.write_object(&data.class_desc)?;
+                self.write_object(&data.constant_name)?;
+            }
+            JavaObject::Class(desc) => {
+                self.writer.write_u8(TC_CLASS)?;
+                self.write_object(desc)?;
+            }
+            JavaObject::Exception(throwable) => {
+                self.writer.write_u8(TC_EXCEPTION)?;
+                self.write_object(throwable)?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    fn write_value(&mut self, value: &Value) -> io::Result<()> {
+        return match value {
+            Value::Byte(v) => self.writer.write_java_byte(*v),
+            Value::Char(v) => self.writer.write_java_char(*v),
+            Value::Double(v) => self.writer.write_f64_be(*v),
+            Value::Float(v) => self.writer.write_f32_be(*v),
+            Value::Int(v) => self.writer.write_java_int(*v),
+            Value::Long(v) => self.writer.write_java_long(*v),
+            Value::Short(v) => self.writer.write_java_short(*v),
+            Value::Boolean(v) => self.writer.write_java_boolean(*v),
+            Value::Object(v) => self.write_object(v),
+        };
+    }
+
+    fn write_array_values(&mut self, values: &ArrayValues) -> io::Result<()> {
+        let len = match values {
+            ArrayValues::Byte(v) => v.len(),
+            ArrayValues::Char(v) => v.len(),
+            ArrayValues::Double(v) => v.len(),
+            ArrayValues::Float(v) => v.len(),
+            ArrayValues::Int(v) => v.len(),
+            ArrayValues::Long(v) => v.len(),
+            ArrayValues::Short(v) => v.len(),
+            ArrayValues::Boolean(v) => v.len(),
+            ArrayValues::Object(v) => v.len(),
+        };
+
+        check_length(len as u64, i32::MAX as u64)?;
+
+        self.writer.write_java_int(len as i32)?;
+        match values {
+            ArrayValues::Byte(v) => v.iter().try_for_each(|e| self.writer.write_java_byte(*e))?,
+            ArrayValues::Char(v) => v.iter().try_for_each(|e| self.writer.write_java_char(*e))?,
+            ArrayValues::Double(v) => v.iter().try_for_each(|e| self.writer.write_f64_be(*e))?,
+            ArrayValues::Float(v) => v.iter().try_for_each(|e| self.writer.write_f32_be(*e))?,
+            ArrayValues::Int(v) => v.iter().try_for_each(|e| self.writer.write_java_int(*e))?,
+            ArrayValues::Long(v) => v.iter().try_for_each(|e| self.writer.write_java_long(*e))?,
+            ArrayValues::Short(v) => v.iter().try_for_each(|e| self.writer.write_java_short(*e))?,
+            ArrayValues::Boolean(v) => {
+                for b in v.iter() {
+                    self.writer.write_java_boolean(*b)?;
+                }
+            }
+            ArrayValues::Object(v) => {
+                for o in v.iter() {
+                    self.write_object(o)?;
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl FromRead for JavaSerializationStream {
+
+    ///
+    /// Reads the stream until EOF. The offset of errors is relative to the position of the reader when this method is called.
+    ///
+    fn copy_from_read(&mut self, reader: &mut dyn Read) -> io::Result<()> {
+        self.contents = CountingReader::new(reader).track_offset(|reader| read_stream(reader))?;
+        return Ok(());
+    }
+}
+
+fn read_stream(mut reader: &mut dyn Read) -> io::Result<Vec<Content>> {
+    if reader.read_java_unsigned_short()? != STREAM_MAGIC {
+        return Err(invalid("invalid stream magic"));
+    }
+
+    if reader.read_java_unsigned_short()? != STREAM_VERSION {
+        return Err(Error::new(ErrorKind::Unsupported, "unsupported stream version"));
+    }
+
+    let mut stream = StreamReader { reader, handles: Vec::new(), depth: 0 };
+    let mut contents = Vec::new();
+    loop {
+        let mut tc = [0u8];
+        match stream.reader.read(&mut tc) {
+            Ok(0) => break,
+            Ok(_) => contents.push(stream.read_content(tc[0]).field("contents")?),
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e)
+        }
+    }
+
+    return Ok(contents);
+}
+
+impl ToWrite for JavaSerializationStream {
+    fn copy_to_write(&self, mut writer: &mut dyn Write) -> io::Result<()> {
+        writer.write_java_char(STREAM_MAGIC)?;
+        writer.write_java_char(STREAM_VERSION)?;
+        let mut stream = StreamWriter { writer };
+        for content in self.contents.iter() {
+            stream.write_content(content)?;
+        }
+
+        return Ok(());
+    }
+}
@@ -1,6 +1,8 @@
 use std::io;
-use std::io::{ErrorKind, Read};
+use std::io::Read;
 use std::mem::size_of;
+use crate::eof::try_read;
+use crate::read_iter::ReadIter;
 
 ///
 /// Trait that provides various methods to read leb128 (little endian base 128) encoded numbers.
@@ -69,6 +71,117 @@ pub trait Leb128Read : private::Sealed {
     /// The max_size parameter controls the maximum size of the returned Vec, not the amount of data to be read.
     ///
     fn read_leb128_large_unsigned(&mut self, max_size: usize) -> io::Result<Vec<u8>>;
+
+    ///
+    /// Reads a uleb128p1 (unsigned leb128 plus 1) as used by the android dex format.
+    /// The encoded value is one larger than the returned value,
+    /// an encoded 0 is therefore returned as u32::MAX which dex uses as NO_INDEX.
+    /// Fails if the leb128 doesn't fit into an u32.
+    ///
+    fn read_leb128p1_u32(&mut self) -> io::Result<u32>;
+
+    ///
+    /// Same as read_leb128_u16 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_u16(&mut self) -> io::Result<Option<u16>>;
+
+    ///
+    /// Same as read_leb128_i16 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_i16(&mut self) -> io::Result<Option<i16>>;
+
+    ///
+    /// Same as read_leb128_u32 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_u32(&mut self) -> io::Result<Option<u32>>;
+
+    ///
+    /// Same as read_leb128_i32 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_i32(&mut self) -> io::Result<Option<i32>>;
+
+    ///
+    /// Same as read_leb128_u64 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_u64(&mut self) -> io::Result<Option<u64>>;
+
+    ///
+    /// Same as read_leb128_i64 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_i64(&mut self) -> io::Result<Option<i64>>;
+
+    ///
+    /// Same as read_leb128_u128 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_u128(&mut self) -> io::Result<Option<u128>>;
+
+    ///
+    /// Same as read_leb128_i128 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128_i128(&mut self) -> io::Result<Option<i128>>;
+
+    ///
+    /// Same as read_leb128p1_u32 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the leb128.
+    ///
+    fn try_read_leb128p1_u32(&mut self) -> io::Result<Option<u32>>;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_u16 until the reader is at EOF.
+    ///
+    fn iter_leb128_u16(&mut self) -> ReadIter<'_, Self, u16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_i16 until the reader is at EOF.
+    ///
+    fn iter_leb128_i16(&mut self) -> ReadIter<'_, Self, i16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_u32 until the reader is at EOF.
+    ///
+    fn iter_leb128_u32(&mut self) -> ReadIter<'_, Self, u32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_i32 until the reader is at EOF.
+    ///
+    fn iter_leb128_i32(&mut self) -> ReadIter<'_, Self, i32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_u64 until the reader is at EOF.
+    ///
+    fn iter_leb128_u64(&mut self) -> ReadIter<'_, Self, u64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_i64 until the reader is at EOF.
+    ///
+    fn iter_leb128_i64(&mut self) -> ReadIter<'_, Self, i64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_u128 until the reader is at EOF.
+    ///
+    fn iter_leb128_u128(&mut self) -> ReadIter<'_, Self, u128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128_i128 until the reader is at EOF.
+    ///
+    fn iter_leb128_i128(&mut self) -> ReadIter<'_, Self, i128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_leb128p1_u32 until the reader is at EOF.
+    ///
+    fn iter_leb128p1_u32(&mut self) -> ReadIter<'_, Self, u32> where Self: Sized;
+}
+
+fn overflow(message: &'static str) -> io::Error {
+    return crate::error::Error::new(crate::error::ErrorKind::Overflow, message).into();
 }
 
 fn next<T: Read>(s: &mut T) -> io::Result<u8> {
@@ -85,7 +198,7 @@ fn finish_large_leb_read(mut shift: u32, mut acc: u64, max_size: usize, mut resu
 
         if result.len()+1 > max_size {
             if shift != 0 {
-                return Err(io::Error::new(ErrorKind::InvalidData, "leb128 larger than desired maximum size"));
+                return Err(overflow("leb128 larger than desired maximum size"));
             }
 
             if is_negative {
@@ -100,7 +213,7 @@ fn finish_large_leb_read(mut shift: u32, mut acc: u64, max_size: usize, mut resu
                 }
             }
 
-            return Err(io::Error::new(ErrorKind::InvalidData, "leb128 larger than desired maximum size"));
+            return Err(overflow("leb128 larger than desired maximum size"));
         }
         result.push(to_push);
         acc >>= 8;
@@ -111,7 +224,7 @@ fn finish_large_leb_read(mut shift: u32, mut acc: u64, max_size: usize, mut resu
 
 fn push_next_large_leb_block(result : &mut Vec<u8>, max_size: usize, acc: u64) -> io::Result<()>{
     if result.len()+7 > max_size {
-        return Err(io::Error::new(ErrorKind::InvalidData, "leb128 larger than desired maximum size"));
+        return Err(overflow("leb128 larger than desired maximum size"));
     }
     result.push(((acc >> 0) & 0xFFu64) as u8);
     result.push(((acc >> 8) & 0xFFu64) as u8);
@@ -132,7 +245,7 @@ macro_rules! read_unsigned_leb {
             let size = (size_of::<$type>() * 8) as u32;
             loop {
                 if shift >= size {
-                    return Err(io::Error::new(ErrorKind::InvalidData, $err));
+                    return Err(overflow($err));
                 }
                 let n : u8 = next(self)?;
                 acc |= ((n & 0b0111_1111u8) as $type) << shift;
@@ -153,7 +266,7 @@ macro_rules! read_signed_leb {
             let size = (size_of::<$helper>() * 8) as u32;
             loop {
                 if shift >= size {
-                    return Err(io::Error::new(ErrorKind::InvalidData, $err));
+                    return Err(overflow($err));
                 }
                 let n : u8 = next(self)?;
                 acc |= ((n & 0b0111_1111u8) as $helper) << shift;
@@ -171,6 +284,26 @@ macro_rules! read_signed_leb {
     }
 }
 
+macro_rules! define_try_read_functions {
+    ($type:ty, $($try_name:ident, $name:ident),+) => {
+        $(
+            fn $try_name(&mut self) -> io::Result<Option<$type>> {
+                return try_read(self, |reader| reader.$name());
+            }
+        )+
+    };
+}
+
+macro_rules! define_iter_functions {
+    ($type:ty, $($iter_name:ident, $try_name:ident),+) => {
+        $(
+            fn $iter_name(&mut self) -> ReadIter<'_, Self, $type> {
+                return ReadIter::new(self, Self::$try_name);
+            }
+        )+
+    };
+}
+
 impl <T> Leb128Read for T where T: Read {
 
     read_signed_leb!(i16, u16, read_leb128_i16, "leb128 larger than i16");
@@ -182,6 +315,27 @@ impl <T> Leb128Read for T where T: Read {
     read_unsigned_leb!(u32, read_leb128_u32, "leb128 larger than u32");
     read_unsigned_leb!(u64, read_leb128_u64, "leb128 larger than u64");
     read_unsigned_leb!(u128, read_leb128_u128, "leb128 larger than u128");
+
+    define_try_read_functions!(i16, try_read_leb128_i16, read_leb128_i16);
+    define_try_read_functions!(i32, try_read_leb128_i32, read_leb128_i32);
+    define_try_read_functions!(i64, try_read_leb128_i64, read_leb128_i64);
+    define_try_read_functions!(i128, try_read_leb128_i128, read_leb128_i128);
+
+    define_try_read_functions!(u16, try_read_leb128_u16, read_leb128_u16);
+    define_try_read_functions!(u32, try_read_leb128_u32, read_leb128_u32, try_read_leb128p1_u32, read_leb128p1_u32);
+    define_try_read_functions!(u64, try_read_leb128_u64, read_leb128_u64);
+    define_try_read_functions!(u128, try_read_leb128_u128, read_leb128_u128);
+
+    define_iter_functions!(i16, iter_leb128_i16, try_read_leb128_i16);
+    define_iter_functions!(i32, iter_leb128_i32, try_read_leb128_i32);
+    define_iter_functions!(i64, iter_leb128_i64, try_read_leb128_i64);
+    define_iter_functions!(i128, iter_leb128_i128, try_read_leb128_i128);
+
+    define_iter_functions!(u16, iter_leb128_u16, try_read_leb128_u16);
+    define_iter_functions!(u32, iter_leb128_u32, try_read_leb128_u32, iter_leb128p1_u32, try_read_leb128p1_u32);
+    define_iter_functions!(u64, iter_leb128_u64, try_read_leb128_u64);
+    define_iter_functions!(u128, iter_leb128_u128, try_read_leb128_u128);
+
     fn read_leb128_large_signed(&mut self, max_size: usize) -> io::Result<Vec<u8>> {
         let mut acc : u64 = 0;
         let mut shift = 0u32;
@@ -224,6 +378,10 @@ impl <T> Leb128Read for T where T: Read {
             }
         }
     }
+
+    fn read_leb128p1_u32(&mut self) -> io::Result<u32> {
+        return Ok(self.read_leb128_u32()?.wrapping_sub(1));
+    }
 }
 
 mod private {
@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::io;
 use std::io::{Error, ErrorKind, Write};
 use std::mem::size_of;
@@ -60,6 +61,57 @@ pub trait Leb128Write : private::Sealed {
     /// The input data is always treated as little endian.
     ///
     fn write_leb128_large_unsigned(&mut self, value: &Vec<u8>) -> io::Result<()>;
+
+    ///
+    /// Write a uleb128p1 (unsigned leb128 plus 1) as used by the android dex format.
+    /// The encoded value is one larger than the given value, u32::MAX (dex NO_INDEX) is encoded as 0.
+    ///
+    fn write_leb128p1_u32(&mut self, value: u32) -> io::Result<()>;
+
+    ///
+    /// Calls write_leb128_u16 for each value of the iterator.
+    ///
+    fn write_all_leb128_u16<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u16>;
+
+    ///
+    /// Calls write_leb128_i16 for each value of the iterator.
+    ///
+    fn write_all_leb128_i16<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i16>;
+
+    ///
+    /// Calls write_leb128_u32 for each value of the iterator.
+    ///
+    fn write_all_leb128_u32<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u32>;
+
+    ///
+    /// Calls write_leb128_i32 for each value of the iterator.
+    ///
+    fn write_all_leb128_i32<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i32>;
+
+    ///
+    /// Calls write_leb128_u64 for each value of the iterator.
+    ///
+    fn write_all_leb128_u64<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u64>;
+
+    ///
+    /// Calls write_leb128_i64 for each value of the iterator.
+    ///
+    fn write_all_leb128_i64<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i64>;
+
+    ///
+    /// Calls write_leb128_u128 for each value of the iterator.
+    ///
+    fn write_all_leb128_u128<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u128>;
+
+    ///
+    /// Calls write_leb128_i128 for each value of the iterator.
+    ///
+    fn write_all_leb128_i128<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i128>;
+
+    ///
+    /// Calls write_leb128p1_u32 for each value of the iterator.
+    ///
+    fn write_all_leb128p1_u32<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u32>;
 }
 
 fn pump_leb128_data<T: Write>(s: &mut T, value: &Vec<u8>, last: usize) -> io::Result<(u64, usize)> {
@@ -151,6 +203,20 @@ macro_rules! write_signed_leb {
 }
 
 
+macro_rules! define_write_all_functions {
+    ($type:ty, $($all_name:ident, $name:ident),+) => {
+        $(
+            fn $all_name<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<$type> {
+                for value in values {
+                    self.$name(*value.borrow())?;
+                }
+
+                return Ok(());
+            }
+        )+
+    };
+}
+
 impl <T> Leb128Write for T where T: Write {
 
     write_unsigned_leb!(u16, write_leb128_u16);
@@ -163,6 +229,16 @@ impl <T> Leb128Write for T where T: Write {
     write_signed_leb!(i64, u64, write_leb128_i64);
     write_signed_leb!(i128, u128, write_leb128_i128);
 
+    define_write_all_functions!(u16, write_all_leb128_u16, write_leb128_u16);
+    define_write_all_functions!(u32, write_all_leb128_u32, write_leb128_u32, write_all_leb128p1_u32, write_leb128p1_u32);
+    define_write_all_functions!(u64, write_all_leb128_u64, write_leb128_u64);
+    define_write_all_functions!(u128, write_all_leb128_u128, write_leb128_u128);
+
+    define_write_all_functions!(i16, write_all_leb128_i16, write_leb128_i16);
+    define_write_all_functions!(i32, write_all_leb128_i32, write_leb128_i32);
+    define_write_all_functions!(i64, write_all_leb128_i64, write_leb128_i64);
+    define_write_all_functions!(i128, write_all_leb128_i128, write_leb128_i128);
+
 
     fn write_leb128_large_signed(&mut self, value: &Vec<u8>) -> io::Result<()> {
         if value.len() == 0 {
@@ -252,6 +328,10 @@ impl <T> Leb128Write for T where T: Write {
             self.write_all(&[next])?;
         }
     }
+
+    fn write_leb128p1_u32(&mut self, value: u32) -> io::Result<()> {
+        return self.write_leb128_u32(value.wrapping_add(1));
+    }
 }
 
 mod private {
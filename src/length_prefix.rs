@@ -0,0 +1,128 @@
+use std::io;
+use std::io::{Seek, SeekFrom, Write};
+use crate::error::check_length;
+use crate::leb128_write::Leb128Write;
+use crate::num_write::NumWrite;
+
+///
+/// Encoding of the length prefix written by LengthPrefixWrite.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PrefixKind {
+    U8,
+    U16LE,
+    U16BE,
+    U32LE,
+    U32BE,
+    U64LE,
+    U64BE,
+
+    ///
+    /// Unsigned leb128, its size depends on the length.
+    ///
+    Leb128,
+}
+
+impl PrefixKind {
+
+    ///
+    /// Returns the size of the prefix in bytes or None for Leb128.
+    ///
+    pub fn size(&self) -> Option<usize> {
+        return match self {
+            PrefixKind::U8 => Some(1),
+            PrefixKind::U16LE | PrefixKind::U16BE => Some(2),
+            PrefixKind::U32LE | PrefixKind::U32BE => Some(4),
+            PrefixKind::U64LE | PrefixKind::U64BE => Some(8),
+            PrefixKind::Leb128 => None,
+        };
+    }
+
+    ///
+    /// Returns the largest length the prefix can represent.
+    ///
+    pub fn max_length(&self) -> u64 {
+        return match self {
+            PrefixKind::U8 => u8::MAX as u64,
+            PrefixKind::U16LE | PrefixKind::U16BE => u16::MAX as u64,
+            PrefixKind::U32LE | PrefixKind::U32BE => u32::MAX as u64,
+            PrefixKind::U64LE | PrefixKind::U64BE | PrefixKind::Leb128 => u64::MAX,
+        };
+    }
+}
+
+fn write_prefix<T: Write>(writer: &mut T, kind: PrefixKind, length: u64) -> io::Result<()> {
+    check_length(length, kind.max_length())?;
+    return match kind {
+        PrefixKind::U8 => writer.write_u8(length as u8),
+        PrefixKind::U16LE => writer.write_u16_le(length as u16),
+        PrefixKind::U16BE => writer.write_u16_be(length as u16),
+        PrefixKind::U32LE => writer.write_u32_le(length as u32),
+        PrefixKind::U32BE => writer.write_u32_be(length as u32),
+        PrefixKind::U64LE => writer.write_u64_le(length),
+        PrefixKind::U64BE => writer.write_u64_be(length),
+        PrefixKind::Leb128 => writer.write_leb128_u64(length),
+    };
+}
+
+///
+/// Trait that provides methods to write data preceded by its length without knowing the length in advance.
+/// Calls can be nested to write nested length prefixed structures.
+/// Automatically implemented for all implementations of io::Write.
+/// This trait is sealed and cannot be implemented manually.
+///
+pub trait LengthPrefixWrite : private::Sealed {
+
+    ///
+    /// Writes everything the body writes to an internal buffer and then writes the length of it as prefix followed by the buffer.
+    /// Fails with InvalidInput if the length does not fit into the prefix, in which case nothing is written.
+    /// Returns the length of the body in bytes.
+    ///
+    fn with_length_prefix<F>(&mut self, kind: PrefixKind, body: F) -> io::Result<u64> where F: FnOnce(&mut dyn Write) -> io::Result<()>;
+
+    ///
+    /// Reserves space for the prefix, lets the body write directly to this writer and then seeks back to write the length.
+    /// This avoids buffering the body but requires a fixed size prefix, Leb128 prefixes are buffered like with_length_prefix.
+    /// The writer is positioned after the body afterwards.
+    /// Fails with InvalidInput if the length does not fit into the prefix. The writer is positioned at the start of the prefix
+    /// in that case, so following writes overwrite the body, which is not truncated.
+    /// Returns the length of the body in bytes.
+    ///
+    fn with_length_prefix_seek<F>(&mut self, kind: PrefixKind, body: F) -> io::Result<u64> where Self: Seek, F: FnOnce(&mut dyn Write) -> io::Result<()>;
+}
+
+impl <T> LengthPrefixWrite for T where T: Write {
+    fn with_length_prefix<F>(&mut self, kind: PrefixKind, body: F) -> io::Result<u64> where F: FnOnce(&mut dyn Write) -> io::Result<()> {
+        let mut buffer: Vec<u8> = Vec::new();
+        body(&mut buffer)?;
+        write_prefix(self, kind, buffer.len() as u64)?;
+        self.write_all(buffer.as_slice())?;
+        return Ok(buffer.len() as u64);
+    }
+
+    fn with_length_prefix_seek<F>(&mut self, kind: PrefixKind, body: F) -> io::Result<u64> where Self: Seek, F: FnOnce(&mut dyn Write) -> io::Result<()> {
+        let size = match kind.size() {
+            Some(size) => size,
+            None => return self.with_length_prefix(kind, body)
+        };
+
+        let prefix_position = self.stream_position()?;
+        self.write_all(&[0u8; 8][..size])?;
+        let start = prefix_position + size as u64;
+        body(self)?;
+        let end = self.stream_position()?;
+        self.seek(SeekFrom::Start(prefix_position))?;
+        write_prefix(self, kind, end - start)?;
+        self.seek(SeekFrom::Start(end))?;
+        return Ok(end - start);
+    }
+}
+
+mod private {
+    use std::io::Write;
+
+    impl <T> Sealed for T where T: Write {}
+    pub trait Sealed {
+
+    }
+}
@@ -1,3 +1,9 @@
+pub mod error;
+pub use error::Error;
+#[cfg(any(feature = "num_read", feature = "leb128_read", feature = "from_read"))]
+mod eof;
+#[cfg(any(feature = "num_read", feature = "leb128_read", feature = "from_read"))]
+pub mod read_iter;
 #[cfg(feature = "num_read")]
 pub mod num_read;
 #[cfg(feature = "num_write")]
@@ -6,6 +12,10 @@ pub mod num_write;
 pub mod string_read;
 #[cfg(feature = "string_write")]
 pub mod string_write;
+#[cfg(any(feature = "string_read", feature = "string_write"))]
+pub mod string_types;
+#[cfg(any(feature = "string_read", feature = "string_write"))]
+pub mod wtf8;
 #[cfg(feature = "to_write")]
 pub mod to_write;
 #[cfg(feature = "from_read")]
@@ -13,4 +23,30 @@ pub mod from_read;
 #[cfg(feature = "leb128_read")]
 pub mod leb128_read;
 #[cfg(feature = "leb128_write")]
-pub mod leb128_write;
\ No newline at end of file
+pub mod leb128_write;
+#[cfg(feature = "java_data_input")]
+pub mod java_data_input;
+#[cfg(feature = "java_data_output")]
+pub mod java_data_output;
+#[cfg(feature = "java_serialization")]
+pub mod java_serialization;
+#[cfg(feature = "classfile")]
+pub mod classfile;
+#[cfg(feature = "dex")]
+pub mod dex;
+#[cfg(feature = "dotnet_binary_read")]
+pub mod dotnet_binary_read;
+#[cfg(feature = "dotnet_binary_write")]
+pub mod dotnet_binary_write;
+#[cfg(any(feature = "dotnet_binary_read", feature = "dotnet_binary_write"))]
+pub mod dotnet_types;
+#[cfg(feature = "transcode")]
+pub mod transcode;
+#[cfg(feature = "counting")]
+pub mod counting;
+#[cfg(feature = "align")]
+pub mod align;
+#[cfg(feature = "length_prefix")]
+pub mod length_prefix;
+#[cfg(feature = "delimited")]
+pub mod delimited;
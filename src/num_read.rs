@@ -1,6 +1,8 @@
 use std::io;
 use std::io::Read;
 use std::mem::size_of;
+use crate::eof::try_read;
+use crate::read_iter::ReadIter;
 
 ///
 /// Trait that provides various methods to read numbers.
@@ -173,6 +175,364 @@ pub trait NumRead : private::Sealed {
     ///
     fn read_f64_ne(&mut self) -> io::Result<f64>;
 
+    ///
+    /// Same as read_bool except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_bool(&mut self) -> io::Result<Option<bool>>;
+
+    ///
+    /// Same as read_u8 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u8(&mut self) -> io::Result<Option<u8>>;
+
+    ///
+    /// Same as read_i8 except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i8(&mut self) -> io::Result<Option<i8>>;
+
+    ///
+    /// Same as read_u16_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u16_le(&mut self) -> io::Result<Option<u16>>;
+
+    ///
+    /// Same as read_u16_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u16_be(&mut self) -> io::Result<Option<u16>>;
+
+    ///
+    /// Same as read_u16_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u16_ne(&mut self) -> io::Result<Option<u16>>;
+
+    ///
+    /// Same as read_u32_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u32_le(&mut self) -> io::Result<Option<u32>>;
+
+    ///
+    /// Same as read_u32_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u32_be(&mut self) -> io::Result<Option<u32>>;
+
+    ///
+    /// Same as read_u32_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u32_ne(&mut self) -> io::Result<Option<u32>>;
+
+    ///
+    /// Same as read_u64_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u64_le(&mut self) -> io::Result<Option<u64>>;
+
+    ///
+    /// Same as read_u64_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u64_be(&mut self) -> io::Result<Option<u64>>;
+
+    ///
+    /// Same as read_u64_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u64_ne(&mut self) -> io::Result<Option<u64>>;
+
+    ///
+    /// Same as read_u128_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u128_le(&mut self) -> io::Result<Option<u128>>;
+
+    ///
+    /// Same as read_u128_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u128_be(&mut self) -> io::Result<Option<u128>>;
+
+    ///
+    /// Same as read_u128_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_u128_ne(&mut self) -> io::Result<Option<u128>>;
+
+    ///
+    /// Same as read_i16_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i16_le(&mut self) -> io::Result<Option<i16>>;
+
+    ///
+    /// Same as read_i16_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i16_be(&mut self) -> io::Result<Option<i16>>;
+
+    ///
+    /// Same as read_i16_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i16_ne(&mut self) -> io::Result<Option<i16>>;
+
+    ///
+    /// Same as read_i32_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i32_le(&mut self) -> io::Result<Option<i32>>;
+
+    ///
+    /// Same as read_i32_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i32_be(&mut self) -> io::Result<Option<i32>>;
+
+    ///
+    /// Same as read_i32_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i32_ne(&mut self) -> io::Result<Option<i32>>;
+
+    ///
+    /// Same as read_i64_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i64_le(&mut self) -> io::Result<Option<i64>>;
+
+    ///
+    /// Same as read_i64_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i64_be(&mut self) -> io::Result<Option<i64>>;
+
+    ///
+    /// Same as read_i64_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i64_ne(&mut self) -> io::Result<Option<i64>>;
+
+    ///
+    /// Same as read_i128_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i128_le(&mut self) -> io::Result<Option<i128>>;
+
+    ///
+    /// Same as read_i128_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i128_be(&mut self) -> io::Result<Option<i128>>;
+
+    ///
+    /// Same as read_i128_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_i128_ne(&mut self) -> io::Result<Option<i128>>;
+
+    ///
+    /// Same as read_f32_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f32_le(&mut self) -> io::Result<Option<f32>>;
+
+    ///
+    /// Same as read_f32_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f32_be(&mut self) -> io::Result<Option<f32>>;
+
+    ///
+    /// Same as read_f32_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f32_ne(&mut self) -> io::Result<Option<f32>>;
+
+    ///
+    /// Same as read_f64_le except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f64_le(&mut self) -> io::Result<Option<f64>>;
+
+    ///
+    /// Same as read_f64_be except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f64_be(&mut self) -> io::Result<Option<f64>>;
+
+    ///
+    /// Same as read_f64_ne except that None is returned if the reader is at EOF before the first byte.
+    /// Fails with UnexpectedEof if the reader ends in the middle of the value.
+    ///
+    fn try_read_f64_ne(&mut self) -> io::Result<Option<f64>>;
+
+    ///
+    /// Returns an iterator that calls try_read_u8 until the reader is at EOF.
+    ///
+    fn iter_u8(&mut self) -> ReadIter<'_, Self, u8> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i8 until the reader is at EOF.
+    ///
+    fn iter_i8(&mut self) -> ReadIter<'_, Self, i8> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u16_le until the reader is at EOF.
+    ///
+    fn iter_u16_le(&mut self) -> ReadIter<'_, Self, u16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u16_be until the reader is at EOF.
+    ///
+    fn iter_u16_be(&mut self) -> ReadIter<'_, Self, u16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u16_ne until the reader is at EOF.
+    ///
+    fn iter_u16_ne(&mut self) -> ReadIter<'_, Self, u16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u32_le until the reader is at EOF.
+    ///
+    fn iter_u32_le(&mut self) -> ReadIter<'_, Self, u32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u32_be until the reader is at EOF.
+    ///
+    fn iter_u32_be(&mut self) -> ReadIter<'_, Self, u32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u32_ne until the reader is at EOF.
+    ///
+    fn iter_u32_ne(&mut self) -> ReadIter<'_, Self, u32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u64_le until the reader is at EOF.
+    ///
+    fn iter_u64_le(&mut self) -> ReadIter<'_, Self, u64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u64_be until the reader is at EOF.
+    ///
+    fn iter_u64_be(&mut self) -> ReadIter<'_, Self, u64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u64_ne until the reader is at EOF.
+    ///
+    fn iter_u64_ne(&mut self) -> ReadIter<'_, Self, u64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u128_le until the reader is at EOF.
+    ///
+    fn iter_u128_le(&mut self) -> ReadIter<'_, Self, u128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u128_be until the reader is at EOF.
+    ///
+    fn iter_u128_be(&mut self) -> ReadIter<'_, Self, u128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_u128_ne until the reader is at EOF.
+    ///
+    fn iter_u128_ne(&mut self) -> ReadIter<'_, Self, u128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i16_le until the reader is at EOF.
+    ///
+    fn iter_i16_le(&mut self) -> ReadIter<'_, Self, i16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i16_be until the reader is at EOF.
+    ///
+    fn iter_i16_be(&mut self) -> ReadIter<'_, Self, i16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i16_ne until the reader is at EOF.
+    ///
+    fn iter_i16_ne(&mut self) -> ReadIter<'_, Self, i16> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i32_le until the reader is at EOF.
+    ///
+    fn iter_i32_le(&mut self) -> ReadIter<'_, Self, i32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i32_be until the reader is at EOF.
+    ///
+    fn iter_i32_be(&mut self) -> ReadIter<'_, Self, i32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i32_ne until the reader is at EOF.
+    ///
+    fn iter_i32_ne(&mut self) -> ReadIter<'_, Self, i32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i64_le until the reader is at EOF.
+    ///
+    fn iter_i64_le(&mut self) -> ReadIter<'_, Self, i64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i64_be until the reader is at EOF.
+    ///
+    fn iter_i64_be(&mut self) -> ReadIter<'_, Self, i64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i64_ne until the reader is at EOF.
+    ///
+    fn iter_i64_ne(&mut self) -> ReadIter<'_, Self, i64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i128_le until the reader is at EOF.
+    ///
+    fn iter_i128_le(&mut self) -> ReadIter<'_, Self, i128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i128_be until the reader is at EOF.
+    ///
+    fn iter_i128_be(&mut self) -> ReadIter<'_, Self, i128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_i128_ne until the reader is at EOF.
+    ///
+    fn iter_i128_ne(&mut self) -> ReadIter<'_, Self, i128> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f32_le until the reader is at EOF.
+    ///
+    fn iter_f32_le(&mut self) -> ReadIter<'_, Self, f32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f32_be until the reader is at EOF.
+    ///
+    fn iter_f32_be(&mut self) -> ReadIter<'_, Self, f32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f32_ne until the reader is at EOF.
+    ///
+    fn iter_f32_ne(&mut self) -> ReadIter<'_, Self, f32> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f64_le until the reader is at EOF.
+    ///
+    fn iter_f64_le(&mut self) -> ReadIter<'_, Self, f64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f64_be until the reader is at EOF.
+    ///
+    fn iter_f64_be(&mut self) -> ReadIter<'_, Self, f64> where Self: Sized;
+
+    ///
+    /// Returns an iterator that calls try_read_f64_ne until the reader is at EOF.
+    ///
+    fn iter_f64_ne(&mut self) -> ReadIter<'_, Self, f64> where Self: Sized;
+
     ///
     /// Reads bytes to fill the slice. Each byte is treated as a u8.
     ///
@@ -779,6 +1139,26 @@ macro_rules! define_endian_read_vec_functions {
     }
 }
 
+macro_rules! define_try_read_functions {
+    ($type:ty, $($try_name:ident, $name:ident),+) => {
+        $(
+            fn $try_name(&mut self) -> io::Result<Option<$type>> {
+                return try_read(self, |reader| reader.$name());
+            }
+        )+
+    };
+}
+
+macro_rules! define_iter_functions {
+    ($type:ty, $($iter_name:ident, $try_name:ident),+) => {
+        $(
+            fn $iter_name(&mut self) -> ReadIter<'_, Self, $type> {
+                return ReadIter::new(self, Self::$try_name);
+            }
+        )+
+    };
+}
+
 impl<T> NumRead for T where T: Read {
     fn read_bool(&mut self) -> io::Result<bool> {
         let mut v = [0u8];
@@ -811,6 +1191,33 @@ impl<T> NumRead for T where T: Read {
     define_endian_numeric_read_functions!(f32, read_f32_le, f32::from_le_bytes, read_f32_be, f32::from_be_bytes, read_f32_ne);
     define_endian_numeric_read_functions!(f64, read_f64_le, f64::from_le_bytes, read_f64_be, f64::from_be_bytes, read_f64_ne);
 
+    define_try_read_functions!(bool, try_read_bool, read_bool);
+    define_try_read_functions!(u8, try_read_u8, read_u8);
+    define_try_read_functions!(i8, try_read_i8, read_i8);
+    define_try_read_functions!(u16, try_read_u16_le, read_u16_le, try_read_u16_be, read_u16_be, try_read_u16_ne, read_u16_ne);
+    define_try_read_functions!(u32, try_read_u32_le, read_u32_le, try_read_u32_be, read_u32_be, try_read_u32_ne, read_u32_ne);
+    define_try_read_functions!(u64, try_read_u64_le, read_u64_le, try_read_u64_be, read_u64_be, try_read_u64_ne, read_u64_ne);
+    define_try_read_functions!(u128, try_read_u128_le, read_u128_le, try_read_u128_be, read_u128_be, try_read_u128_ne, read_u128_ne);
+    define_try_read_functions!(i16, try_read_i16_le, read_i16_le, try_read_i16_be, read_i16_be, try_read_i16_ne, read_i16_ne);
+    define_try_read_functions!(i32, try_read_i32_le, read_i32_le, try_read_i32_be, read_i32_be, try_read_i32_ne, read_i32_ne);
+    define_try_read_functions!(i64, try_read_i64_le, read_i64_le, try_read_i64_be, read_i64_be, try_read_i64_ne, read_i64_ne);
+    define_try_read_functions!(i128, try_read_i128_le, read_i128_le, try_read_i128_be, read_i128_be, try_read_i128_ne, read_i128_ne);
+    define_try_read_functions!(f32, try_read_f32_le, read_f32_le, try_read_f32_be, read_f32_be, try_read_f32_ne, read_f32_ne);
+    define_try_read_functions!(f64, try_read_f64_le, read_f64_le, try_read_f64_be, read_f64_be, try_read_f64_ne, read_f64_ne);
+
+    define_iter_functions!(u8, iter_u8, try_read_u8);
+    define_iter_functions!(i8, iter_i8, try_read_i8);
+    define_iter_functions!(u16, iter_u16_le, try_read_u16_le, iter_u16_be, try_read_u16_be, iter_u16_ne, try_read_u16_ne);
+    define_iter_functions!(u32, iter_u32_le, try_read_u32_le, iter_u32_be, try_read_u32_be, iter_u32_ne, try_read_u32_ne);
+    define_iter_functions!(u64, iter_u64_le, try_read_u64_le, iter_u64_be, try_read_u64_be, iter_u64_ne, try_read_u64_ne);
+    define_iter_functions!(u128, iter_u128_le, try_read_u128_le, iter_u128_be, try_read_u128_be, iter_u128_ne, try_read_u128_ne);
+    define_iter_functions!(i16, iter_i16_le, try_read_i16_le, iter_i16_be, try_read_i16_be, iter_i16_ne, try_read_i16_ne);
+    define_iter_functions!(i32, iter_i32_le, try_read_i32_le, iter_i32_be, try_read_i32_be, iter_i32_ne, try_read_i32_ne);
+    define_iter_functions!(i64, iter_i64_le, try_read_i64_le, iter_i64_be, try_read_i64_be, iter_i64_ne, try_read_i64_ne);
+    define_iter_functions!(i128, iter_i128_le, try_read_i128_le, iter_i128_be, try_read_i128_be, iter_i128_ne, try_read_i128_ne);
+    define_iter_functions!(f32, iter_f32_le, try_read_f32_le, iter_f32_be, try_read_f32_be, iter_f32_ne, try_read_f32_ne);
+    define_iter_functions!(f64, iter_f64_le, try_read_f64_le, iter_f64_be, try_read_f64_be, iter_f64_ne, try_read_f64_ne);
+
 
 
 
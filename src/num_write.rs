@@ -1,3 +1,4 @@
+use std::borrow::Borrow;
 use std::io;
 use std::io::Write;
 use std::mem::size_of;
@@ -173,6 +174,166 @@ pub trait NumWrite : private::Sealed {
     ///
     fn write_f64_ne(&mut self, value: f64) -> io::Result<()>;
 
+    ///
+    /// Calls write_u8 for each value of the iterator.
+    ///
+    fn write_all_u8<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u8>;
+
+    ///
+    /// Calls write_i8 for each value of the iterator.
+    ///
+    fn write_all_i8<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i8>;
+
+    ///
+    /// Calls write_u16_le for each value of the iterator.
+    ///
+    fn write_all_u16_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u16>;
+
+    ///
+    /// Calls write_u16_be for each value of the iterator.
+    ///
+    fn write_all_u16_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u16>;
+
+    ///
+    /// Calls write_u16_ne for each value of the iterator.
+    ///
+    fn write_all_u16_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u16>;
+
+    ///
+    /// Calls write_u32_le for each value of the iterator.
+    ///
+    fn write_all_u32_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u32>;
+
+    ///
+    /// Calls write_u32_be for each value of the iterator.
+    ///
+    fn write_all_u32_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u32>;
+
+    ///
+    /// Calls write_u32_ne for each value of the iterator.
+    ///
+    fn write_all_u32_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u32>;
+
+    ///
+    /// Calls write_u64_le for each value of the iterator.
+    ///
+    fn write_all_u64_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u64>;
+
+    ///
+    /// Calls write_u64_be for each value of the iterator.
+    ///
+    fn write_all_u64_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u64>;
+
+    ///
+    /// Calls write_u64_ne for each value of the iterator.
+    ///
+    fn write_all_u64_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u64>;
+
+    ///
+    /// Calls write_u128_le for each value of the iterator.
+    ///
+    fn write_all_u128_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u128>;
+
+    ///
+    /// Calls write_u128_be for each value of the iterator.
+    ///
+    fn write_all_u128_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u128>;
+
+    ///
+    /// Calls write_u128_ne for each value of the iterator.
+    ///
+    fn write_all_u128_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<u128>;
+
+    ///
+    /// Calls write_i16_le for each value of the iterator.
+    ///
+    fn write_all_i16_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i16>;
+
+    ///
+    /// Calls write_i16_be for each value of the iterator.
+    ///
+    fn write_all_i16_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i16>;
+
+    ///
+    /// Calls write_i16_ne for each value of the iterator.
+    ///
+    fn write_all_i16_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i16>;
+
+    ///
+    /// Calls write_i32_le for each value of the iterator.
+    ///
+    fn write_all_i32_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i32>;
+
+    ///
+    /// Calls write_i32_be for each value of the iterator.
+    ///
+    fn write_all_i32_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i32>;
+
+    ///
+    /// Calls write_i32_ne for each value of the iterator.
+    ///
+    fn write_all_i32_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i32>;
+
+    ///
+    /// Calls write_i64_le for each value of the iterator.
+    ///
+    fn write_all_i64_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i64>;
+
+    ///
+    /// Calls write_i64_be for each value of the iterator.
+    ///
+    fn write_all_i64_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i64>;
+
+    ///
+    /// Calls write_i64_ne for each value of the iterator.
+    ///
+    fn write_all_i64_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i64>;
+
+    ///
+    /// Calls write_i128_le for each value of the iterator.
+    ///
+    fn write_all_i128_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i128>;
+
+    ///
+    /// Calls write_i128_be for each value of the iterator.
+    ///
+    fn write_all_i128_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i128>;
+
+    ///
+    /// Calls write_i128_ne for each value of the iterator.
+    ///
+    fn write_all_i128_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<i128>;
+
+    ///
+    /// Calls write_f32_le for each value of the iterator.
+    ///
+    fn write_all_f32_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f32>;
+
+    ///
+    /// Calls write_f32_be for each value of the iterator.
+    ///
+    fn write_all_f32_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f32>;
+
+    ///
+    /// Calls write_f32_ne for each value of the iterator.
+    ///
+    fn write_all_f32_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f32>;
+
+    ///
+    /// Calls write_f64_le for each value of the iterator.
+    ///
+    fn write_all_f64_le<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f64>;
+
+    ///
+    /// Calls write_f64_be for each value of the iterator.
+    ///
+    fn write_all_f64_be<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f64>;
+
+    ///
+    /// Calls write_f64_ne for each value of the iterator.
+    ///
+    fn write_all_f64_ne<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<f64>;
+
     ///
     /// Writes the entire slice.
     ///
@@ -592,6 +753,20 @@ macro_rules! define_endian_vec_write_functions {
     };
 }
 
+macro_rules! define_write_all_functions {
+    ($type:ty, $($all_name:ident, $name:ident),+) => {
+        $(
+            fn $all_name<I>(&mut self, values: I) -> io::Result<()> where I: IntoIterator, I::Item: Borrow<$type> {
+                for value in values {
+                    self.$name(*value.borrow())?;
+                }
+
+                return Ok(());
+            }
+        )+
+    };
+}
+
 impl<T> NumWrite for T where T: Write {
     fn write_bool(&mut self, value: bool) -> io::Result<()> {
         if value {
@@ -609,6 +784,21 @@ impl<T> NumWrite for T where T: Write {
         return self.write_all(&[value as u8]);
     }
 
+    define_write_all_functions!(u8, write_all_u8, write_u8);
+    define_write_all_functions!(i8, write_all_i8, write_i8);
+
+    define_write_all_functions!(u16, write_all_u16_le, write_u16_le, write_all_u16_be, write_u16_be, write_all_u16_ne, write_u16_ne);
+    define_write_all_functions!(u32, write_all_u32_le, write_u32_le, write_all_u32_be, write_u32_be, write_all_u32_ne, write_u32_ne);
+    define_write_all_functions!(u64, write_all_u64_le, write_u64_le, write_all_u64_be, write_u64_be, write_all_u64_ne, write_u64_ne);
+    define_write_all_functions!(u128, write_all_u128_le, write_u128_le, write_all_u128_be, write_u128_be, write_all_u128_ne, write_u128_ne);
+    define_write_all_functions!(i16, write_all_i16_le, write_i16_le, write_all_i16_be, write_i16_be, write_all_i16_ne, write_i16_ne);
+    define_write_all_functions!(i32, write_all_i32_le, write_i32_le, write_all_i32_be, write_i32_be, write_all_i32_ne, write_i32_ne);
+    define_write_all_functions!(i64, write_all_i64_le, write_i64_le, write_all_i64_be, write_i64_be, write_all_i64_ne, write_i64_ne);
+    define_write_all_functions!(i128, write_all_i128_le, write_i128_le, write_all_i128_be, write_i128_be, write_all_i128_ne, write_i128_ne);
+    define_write_all_functions!(f32, write_all_f32_le, write_f32_le, write_all_f32_be, write_f32_be, write_all_f32_ne, write_f32_ne);
+    define_write_all_functions!(f64, write_all_f64_le, write_f64_le, write_all_f64_be, write_f64_be, write_all_f64_ne, write_f64_ne);
+
+
     define_endian_numeric_write_functions!(u16, write_u16_le, write_u16_be, write_u16_ne);
     define_endian_numeric_write_functions!(u32, write_u32_le, write_u32_be, write_u32_ne);
     define_endian_numeric_write_functions!(u64, write_u64_le, write_u64_be, write_u64_ne);
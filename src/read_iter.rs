@@ -0,0 +1,47 @@
+use std::io;
+
+///
+/// Iterator that reads values from a reader until it is at EOF.
+///
+/// Each item is the result of reading one value. The iterator ends without an error if the reader is at EOF
+/// before the first byte of a value. If the reader ends in the middle of a value or any other error occurs,
+/// the error is yielded as the last item.
+///
+/// Created by the iter_* methods of NumRead and Leb128Read and by RecordRead::iter_records.
+///
+pub struct ReadIter<'a, R: ?Sized, T> {
+    reader: &'a mut R,
+    read: fn(&mut R) -> io::Result<Option<T>>,
+    done: bool,
+}
+
+impl <'a, R: ?Sized, T> ReadIter<'a, R, T> {
+
+    pub(crate) fn new(reader: &'a mut R, read: fn(&mut R) -> io::Result<Option<T>>) -> ReadIter<'a, R, T> {
+        return ReadIter { reader, read, done: false };
+    }
+}
+
+impl <'a, R: ?Sized, T> Iterator for ReadIter<'a, R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        return match (self.read)(self.reader) {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        };
+    }
+}
+
+impl <'a, R: ?Sized, T> std::iter::FusedIterator for ReadIter<'a, R, T> {}
@@ -0,0 +1,41 @@
+///
+/// Text encodings supported by the string read/write methods that take the encoding as a parameter.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16LE,
+    Utf16BE,
+    Utf32LE,
+    Utf32BE,
+}
+
+impl TextEncoding {
+    ///
+    /// Returns the size of a single code unit of this encoding in bytes.
+    ///
+    pub fn unit_size(&self) -> usize {
+        return match self {
+            TextEncoding::Utf8 => 1,
+            TextEncoding::Utf16LE | TextEncoding::Utf16BE => 2,
+            TextEncoding::Utf32LE | TextEncoding::Utf32BE => 4,
+        };
+    }
+}
+
+///
+/// Padding used to fill the unused remainder of a fixed width string field.
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Padding {
+    ///
+    /// The field is filled with null chars. When reading, the string ends at the first null char.
+    /// This is how C char arrays are usually stored.
+    ///
+    Zero,
+
+    ///
+    /// The field is filled with space chars. When reading, all trailing spaces are removed.
+    ///
+    Space,
+}
@@ -3,6 +3,9 @@ use std::io::{Error, ErrorKind, Write};
 use std::mem::size_of;
 use encoding::{Encoding, EncoderTrap};
 use encoding::all::{UTF_16BE, UTF_16LE};
+use crate::error::check_length;
+use crate::string_types::{Padding, TextEncoding};
+use crate::wtf8::Wtf8Buf;
 
 ///
 /// Trait that provides various methods to write strings.
@@ -13,36 +16,105 @@ pub trait StringWrite : private::Sealed {
 
     ///
     /// Writes an u16 little endian length prefix followed by an utf-8 representation of the string
+    /// Fails with an Overflow error if the string is longer than u16::MAX bytes.
     /// Returns the total amount of bytes written
     ///
     fn write_string_u16_le_len_utf8(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Same as write_string_u16_le_len_utf8 except that strings longer than u16::MAX bytes are
+    /// truncated at the last char boundary that fits instead of failing.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_u16_le_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize>;
+
     ///
     /// Writes an u16 big endian length prefix followed by an utf-8 representation of the string
+    /// Fails with an Overflow error if the string is longer than u16::MAX bytes.
     /// Returns the total amount of bytes written
     ///
     fn write_string_u16_be_len_utf8(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Same as write_string_u16_be_len_utf8 except that strings longer than u16::MAX bytes are
+    /// truncated at the last char boundary that fits instead of failing.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_u16_be_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize>;
+
     ///
     /// Writes an u32 little endian length prefix followed by an utf-8 representation of the string
+    /// Fails with an Overflow error if the string is longer than u32::MAX bytes.
     /// Returns the total amount of bytes written
     ///
     fn write_string_u32_le_len_utf8(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Same as write_string_u32_le_len_utf8 except that strings longer than u32::MAX bytes are
+    /// truncated at the last char boundary that fits instead of failing.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_u32_le_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize>;
+
     ///
     /// Writes an u32 big endian length prefix followed by an utf-8 representation of the string
+    /// Fails with an Overflow error if the string is longer than u32::MAX bytes.
     /// Returns the total amount of bytes written
     ///
     fn write_string_u32_be_len_utf8(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Same as write_string_u32_be_len_utf8 except that strings longer than u32::MAX bytes are
+    /// truncated at the last char boundary that fits instead of failing.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_u32_be_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize>;
+
     ///
     /// Writes an utf-8 representation of the string and a zero byte.
     /// If the string ends with a null char then no zero byte is appended.
+    /// An empty string is written as a single zero byte.
     /// If the string contains a null char in the middle then the method fails.
     /// Returns the total amount of bytes written
     ///
     fn write_string_zero_terminated_utf8(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Writes an utf-16-le representation of the string and a zero u16.
+    /// If the string ends with a null char then no zero u16 is appended.
+    /// An empty string is written as a single zero u16.
+    /// If the string contains a null char in the middle then the method fails.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_zero_terminated_utf16_le(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes an utf-16-be representation of the string and a zero u16.
+    /// If the string ends with a null char then no zero u16 is appended.
+    /// An empty string is written as a single zero u16.
+    /// If the string contains a null char in the middle then the method fails.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_zero_terminated_utf16_be(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes an utf-32-le representation of the string and a zero u32.
+    /// If the string ends with a null char then no zero u32 is appended.
+    /// An empty string is written as a single zero u32.
+    /// If the string contains a null char in the middle then the method fails.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_zero_terminated_utf32_le(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes an utf-32-be representation of the string and a zero u32.
+    /// If the string ends with a null char then no zero u32 is appended.
+    /// An empty string is written as a single zero u32.
+    /// If the string contains a null char in the middle then the method fails.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_zero_terminated_utf32_be(&mut self, string: &str) -> io::Result<usize>;
+
     ///
     /// Writes an utf-8 representation of the string
     /// Returns the total amount of bytes written
@@ -61,6 +133,20 @@ pub trait StringWrite : private::Sealed {
     ///
     fn write_string_utf16_le(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Writes an utf-16-be representation of the WTF-8 buffer.
+    /// Unpaired surrogates contained in the buffer are written as is.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_wtf8_utf16_be(&mut self, string: &Wtf8Buf) -> io::Result<usize>;
+
+    ///
+    /// Writes an utf-16-le representation of the WTF-8 buffer.
+    /// Unpaired surrogates contained in the buffer are written as is.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_wtf8_utf16_le(&mut self, string: &Wtf8Buf) -> io::Result<usize>;
+
     ///
     /// Writes an utf-32-be representation of the string
     /// Returns the total amount of bytes written
@@ -73,8 +159,24 @@ pub trait StringWrite : private::Sealed {
     ///
     fn write_string_utf32_le(&mut self, string: &str) -> io::Result<usize>;
 
+    ///
+    /// Writes the byte order mark of the given encoding followed by the string in that encoding.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_bom(&mut self, string: &str, encoding: TextEncoding) -> io::Result<usize>;
+
+    ///
+    /// Writes the string in the given encoding and fills the remainder of the
+    /// field with padding so that exactly width bytes are written.
+    /// Fails if the string does not fit into the field, if width is not a multiple of the
+    /// code unit size of the encoding or if the padding is Zero and the string contains a null char.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_fixed(&mut self, string: &str, width: usize, encoding: TextEncoding, padding: Padding) -> io::Result<usize>;
+
     ///
     /// Writes a string that can be read by a java program using the java.io.DataInput#readUTF facility.
+    /// Fails with an Overflow error if the encoded string is longer than 65535 bytes.
     /// In general, it writes a big endian u16 to indicate how many bytes it will write.
     /// Each character is a re-encoded utf-16 representation of the string.
     /// 1. The string is utf-16 encoded
@@ -82,53 +184,227 @@ pub trait StringWrite : private::Sealed {
     /// Returns the total amount of bytes written
     ///
     fn write_java_data_output_utf(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Same as write_java_data_output_utf except that strings whose encoding is longer than 65535 bytes are
+    /// truncated at the last char boundary that fits instead of failing.
+    /// Surrogate pairs are never split.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_data_output_utf_truncate(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes the string encoded as java's modified utf-8 without any length prefix.
+    /// This is the encoding used by java.io.DataOutput#writeUTF, jvm class files and jni.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_modified_utf8(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes the string encoded as java's modified utf-8 followed by a zero byte.
+    /// This is how strings are stored in android dex files.
+    /// Null chars in the string do not cause problems as java's modified utf-8 never contains a zero byte.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_java_modified_utf8_zero_terminated(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes the string encoded as CESU-8.
+    /// CESU-8 is identical to java's modified utf-8 except that the null char is encoded as a single zero byte.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_string_cesu8(&mut self, string: &str) -> io::Result<usize>;
+
+    ///
+    /// Writes the char utf-8 encoded.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_utf8(&mut self, value: char) -> io::Result<usize>;
+
+    ///
+    /// Writes the char utf-16-le encoded, chars outside the basic multilingual plane are written as surrogate pair.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_utf16_le(&mut self, value: char) -> io::Result<usize>;
+
+    ///
+    /// Writes the char utf-16-be encoded, chars outside the basic multilingual plane are written as surrogate pair.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_utf16_be(&mut self, value: char) -> io::Result<usize>;
+
+    ///
+    /// Writes the char utf-32-le encoded.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_utf32_le(&mut self, value: char) -> io::Result<usize>;
+
+    ///
+    /// Writes the char utf-32-be encoded.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_utf32_be(&mut self, value: char) -> io::Result<usize>;
+
+    ///
+    /// Writes the char encoded as java's modified utf-8.
+    /// Returns the total amount of bytes written
+    ///
+    fn write_char_java_modified_utf8(&mut self, value: char) -> io::Result<usize>;
 }
 
 
 const ZERO: [u8; 1] = [0u8];
+const ZERO_UTF16: [u8; 2] = [0u8; 2];
+const ZERO_UTF32: [u8; 4] = [0u8; 4];
+
+///
+/// Checks that the string has no null char except for possibly its last char.
+/// Returns true if a zero terminator still has to be appended, which is always the case for an empty string.
+///
+fn needs_zero_terminator(string: &str) -> io::Result<bool> {
+    let mut chars = string.chars();
+    let last = match chars.next_back() {
+        Some(last) => last,
+        None => return Ok(true)
+    };
+
+    if chars.any(|c| c == '\0') {
+        return Err(Error::new(ErrorKind::InvalidInput, "Null char found in string"));
+    }
+
+    return Ok(last != '\0');
+}
+///
+/// Encodes the string as java's modified utf-8 or CESU-8.
+/// Each u16 of the utf-16 representation of the string is encoded as 1, 2 or 3 bytes.
+/// The encodings only differ in how the null char is encoded.
+/// Java uses the 2 byte sequence 0xC0 0x80 and CESU-8 a single zero byte.
+///
+fn encode_modified_utf8(string: &str, cesu8: bool) -> Vec<u8> {
+    //This is optimistic alloc and works if we only send ascii;
+    let mut data: Vec<u8> = Vec::with_capacity(string.len());
+    for c in string.encode_utf16() {
+        if c < 0x80 && (c != 0 || cesu8) {
+            data.push(c as u8);
+            continue;
+        }
+
+        if c >= 0x800 {
+            data.push((0xE0 | ((c >> 12) & 0x0F)) as u8);
+            data.push((0x80 | ((c >>  6) & 0x3F)) as u8);
+            data.push((0x80 | ((c >>  0) & 0x3F)) as u8);
+            continue;
+        }
+
+        data.push((0xC0 | ((c >>  6) & 0x1F)) as u8);
+        data.push((0x80 | ((c >>  0) & 0x3F)) as u8);
+    }
+
+    return data;
+}
+
+///
+/// Returns the longest prefix of the string that is at most max_len bytes long and ends on a char boundary.
+///
+fn truncate_utf8(string: &str, max_len: usize) -> &str {
+    if string.len() <= max_len {
+        return string;
+    }
+
+    let mut len = max_len;
+    while !string.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    return &string[..len];
+}
+
+///
+/// Returns the longest prefix of the string whose java modified utf-8 encoding is at most max_len bytes long.
+///
+fn truncate_modified_utf8(string: &str, max_len: usize) -> &str {
+    let mut len = 0;
+    for (index, c) in string.char_indices() {
+        len += match c as u32 {
+            0 => 2,
+            0x01..=0x7F => 1,
+            0x80..=0x7FF => 2,
+            0x800..=0xFFFF => 3,
+            _ => 6
+        };
+
+        if len > max_len {
+            return &string[..index];
+        }
+    }
+
+    return string;
+}
+
+fn write_encoded<T: Write>(writer: &mut T, string: &str, encoding: TextEncoding) -> io::Result<usize> {
+    return match encoding {
+        TextEncoding::Utf8 => writer.write_string_utf8(string),
+        TextEncoding::Utf16LE => writer.write_string_utf16_le(string),
+        TextEncoding::Utf16BE => writer.write_string_utf16_be(string),
+        TextEncoding::Utf32LE => writer.write_string_utf32_le(string),
+        TextEncoding::Utf32BE => writer.write_string_utf32_be(string),
+    };
+}
+
 impl <T> StringWrite for T where T: Write {
     fn write_string_u16_le_len_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
-        let len_u16 = x.len() as u16;
-        if x.len() != len_u16 as usize {
-            return Err(Error::new(ErrorKind::Other, "string too long"));
-        }
-
-        self.write_all(len_u16.to_le_bytes().as_slice())?;
+        check_length(x.len() as u64, u16::MAX as u64)?;
+        self.write_all((x.len() as u16).to_le_bytes().as_slice())?;
         self.write_all(x)?;
         return Ok(x.len()+2);
     }
 
+    fn write_string_u16_le_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_string_u16_le_len_utf8(truncate_utf8(string, u16::MAX as usize));
+    }
+
     fn write_string_u16_be_len_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
-        let len_u16 = x.len() as u16;
-        if x.len() != len_u16 as usize {
-            return Err(Error::new(ErrorKind::Other, "string too long"));
-        }
-
-        self.write_all(len_u16.to_be_bytes().as_slice())?;
+        check_length(x.len() as u64, u16::MAX as u64)?;
+        self.write_all((x.len() as u16).to_be_bytes().as_slice())?;
         self.write_all(x)?;
         return Ok(x.len()+2);
     }
 
+    fn write_string_u16_be_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_string_u16_be_len_utf8(truncate_utf8(string, u16::MAX as usize));
+    }
+
     fn write_string_u32_le_len_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
+        check_length(x.len() as u64, u32::MAX as u64)?;
         self.write_all((x.len() as u32).to_le_bytes().as_slice())?;
         self.write_all(x)?;
         return Ok(x.len()+4);
     }
 
+    fn write_string_u32_le_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_string_u32_le_len_utf8(truncate_utf8(string, u32::MAX as usize));
+    }
+
     fn write_string_u32_be_len_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
+        check_length(x.len() as u64, u32::MAX as u64)?;
         self.write_all((x.len() as u32).to_be_bytes().as_slice())?;
         self.write_all(x)?;
         return Ok(x.len()+4);
     }
 
+    fn write_string_u32_be_len_utf8_truncate(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_string_u32_be_len_utf8(truncate_utf8(string, u32::MAX as usize));
+    }
+
     fn write_string_zero_terminated_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
         if x.len() == 0 {
-            return Ok(0);
+            self.write_all(&ZERO)?;
+            return Ok(1);
         }
 
         for i in 0 .. x.len()-1 {
@@ -147,6 +423,50 @@ impl <T> StringWrite for T where T: Write {
         return Ok(x.len()+1);
     }
 
+    fn write_string_zero_terminated_utf16_le(&mut self, string: &str) -> io::Result<usize> {
+        let terminate = needs_zero_terminator(string)?;
+        let count = self.write_string_utf16_le(string)?;
+        if !terminate {
+            return Ok(count);
+        }
+
+        self.write_all(&ZERO_UTF16)?;
+        return Ok(count+2);
+    }
+
+    fn write_string_zero_terminated_utf16_be(&mut self, string: &str) -> io::Result<usize> {
+        let terminate = needs_zero_terminator(string)?;
+        let count = self.write_string_utf16_be(string)?;
+        if !terminate {
+            return Ok(count);
+        }
+
+        self.write_all(&ZERO_UTF16)?;
+        return Ok(count+2);
+    }
+
+    fn write_string_zero_terminated_utf32_le(&mut self, string: &str) -> io::Result<usize> {
+        let terminate = needs_zero_terminator(string)?;
+        let count = self.write_string_utf32_le(string)?;
+        if !terminate {
+            return Ok(count);
+        }
+
+        self.write_all(&ZERO_UTF32)?;
+        return Ok(count+4);
+    }
+
+    fn write_string_zero_terminated_utf32_be(&mut self, string: &str) -> io::Result<usize> {
+        let terminate = needs_zero_terminator(string)?;
+        let count = self.write_string_utf32_be(string)?;
+        if !terminate {
+            return Ok(count);
+        }
+
+        self.write_all(&ZERO_UTF32)?;
+        return Ok(count+4);
+    }
+
     fn write_string_utf8(&mut self, string: &str) -> io::Result<usize> {
         let x = string.as_bytes();
         self.write_all(x)?;
@@ -167,6 +487,18 @@ impl <T> StringWrite for T where T: Write {
         return Ok(encoded.len());
     }
 
+    fn write_wtf8_utf16_be(&mut self, string: &Wtf8Buf) -> io::Result<usize> {
+        let data: Vec<u8> = string.to_utf16().iter().flat_map(|u| u.to_be_bytes()).collect();
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
+
+    fn write_wtf8_utf16_le(&mut self, string: &Wtf8Buf) -> io::Result<usize> {
+        let data: Vec<u8> = string.to_utf16().iter().flat_map(|u| u.to_le_bytes()).collect();
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
+
     #[cfg(target_endian = "big")]
     fn write_string_utf32_be(&mut self, string: &str) -> io::Result<usize> {
         let mut data: Vec<char> = string.chars().collect();
@@ -207,63 +539,116 @@ impl <T> StringWrite for T where T: Write {
         return Ok(sl.len());
     }
 
-    fn write_java_data_output_utf(&mut self, string: &str) -> io::Result<usize> {
-        let encoded = UTF_16LE.encode(string, EncoderTrap::Strict)
-            .map_err(|e| Error::new(ErrorKind::Other, e.as_ref()))?;
+    fn write_string_bom(&mut self, string: &str, encoding: TextEncoding) -> io::Result<usize> {
+        let bom = write_encoded(self, "\u{FEFF}", encoding)?;
+        return Ok(bom + write_encoded(self, string, encoding)?);
+    }
 
-        if encoded.len() & 1 != 0 {
-            panic!("UTF-16 encoder failed and gave a odd number of bytes as output without reporting an error!");
+    fn write_string_fixed(&mut self, string: &str, width: usize, encoding: TextEncoding, padding: Padding) -> io::Result<usize> {
+        if width % encoding.unit_size() != 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "width is not a multiple of the code unit size"));
         }
 
-        let sl : &[u16] = unsafe { std::slice::from_raw_parts(encoded.as_ptr().cast(), encoded.len() >> 1) };
-        let mut count : usize = 0;
-        for c_le in sl {
-            let c = c_le.to_le();
-            if c < 0x80 && c != 0 {
-                count+=1;
-                continue;
-            }
-            if c >= 0x800 {
-                count+=3;
-                continue;
-            }
-            count+=2;
+        if padding == Padding::Zero && string.contains('\0') {
+            return Err(Error::new(ErrorKind::InvalidInput, "Null char found in string"));
         }
 
-        if count > 65535 {
-            return Err(Error::new(ErrorKind::Other, "String length exceeds maximum allowed value"));
+        let mut data: Vec<u8> = Vec::with_capacity(width);
+        write_encoded(&mut data, string, encoding)?;
+        if data.len() > width {
+            return Err(Error::new(ErrorKind::InvalidInput, "string does not fit into fixed width field"));
         }
 
-        let mut data : Vec<u8> = vec![0u8; count+2];
-        let mut index = 2usize;
+        let mut pad: Vec<u8> = Vec::with_capacity(4);
+        match padding {
+            Padding::Zero => write_encoded(&mut pad, "\0", encoding)?,
+            Padding::Space => write_encoded(&mut pad, " ", encoding)?,
+        };
+
+        while data.len() < width {
+            data.extend_from_slice(pad.as_slice());
+        }
+
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
+
+    fn write_java_data_output_utf(&mut self, string: &str) -> io::Result<usize> {
+        let data = encode_modified_utf8(string, false);
+        let count = data.len();
+        check_length(count as u64, 65535)?;
+
         //This is always big endian in java.
-        data[0] = ((count >> 8)& 0xFFusize) as u8;
-        data[1] = ((count >> 0)& 0xFFusize) as u8;
-
-        for c_le in sl {
-            let c = c_le.to_le();
-            if c < 0x80 && c != 0 {
-                data[index] = c as u8;
-                index+=1;
-                continue;
-            }
+        self.write_all((count as u16).to_be_bytes().as_slice())?;
+        self.write_all(data.as_slice())?;
+        return Ok(count+2);
+    }
 
-            if c >= 0x800 {
-                data[index] = (0xE0 | ((c >> 12) & 0x0F)) as u8;
-                data[index+1] = (0x80 | ((c >>  6) & 0x3F)) as u8;
-                data[index+2] = (0x80 | ((c >>  0) & 0x3F)) as u8;
-                index+=3;
-                continue;
-            }
+    fn write_java_data_output_utf_truncate(&mut self, string: &str) -> io::Result<usize> {
+        return self.write_java_data_output_utf(truncate_modified_utf8(string, 65535));
+    }
 
-            data[index] = (0xC0 | ((c >>  6) & 0x1F)) as u8;
-            data[index+1] = (0x80 | ((c >>  0) & 0x3F)) as u8;
-            index+=2;
-        }
+    fn write_java_modified_utf8(&mut self, string: &str) -> io::Result<usize> {
+        let data = encode_modified_utf8(string, false);
+        self.write_all(data.as_slice())?;
+        return Ok(data.len());
+    }
 
+    fn write_java_modified_utf8_zero_terminated(&mut self, string: &str) -> io::Result<usize> {
+        let count = self.write_java_modified_utf8(string)?;
+        self.write_all(&ZERO)?;
+        return Ok(count+1);
+    }
+
+    fn write_string_cesu8(&mut self, string: &str) -> io::Result<usize> {
+        let data = encode_modified_utf8(string, true);
         self.write_all(data.as_slice())?;
         return Ok(data.len());
     }
+
+    fn write_char_utf8(&mut self, value: char) -> io::Result<usize> {
+        let mut buf = [0u8; 4];
+        let encoded = value.encode_utf8(&mut buf);
+        self.write_all(encoded.as_bytes())?;
+        return Ok(encoded.len());
+    }
+
+    fn write_char_utf16_le(&mut self, value: char) -> io::Result<usize> {
+        let mut buf = [0u16; 2];
+        let mut count = 0;
+        for unit in value.encode_utf16(&mut buf).iter() {
+            self.write_all(&unit.to_le_bytes())?;
+            count += 2;
+        }
+
+        return Ok(count);
+    }
+
+    fn write_char_utf16_be(&mut self, value: char) -> io::Result<usize> {
+        let mut buf = [0u16; 2];
+        let mut count = 0;
+        for unit in value.encode_utf16(&mut buf).iter() {
+            self.write_all(&unit.to_be_bytes())?;
+            count += 2;
+        }
+
+        return Ok(count);
+    }
+
+    fn write_char_utf32_le(&mut self, value: char) -> io::Result<usize> {
+        self.write_all(&(value as u32).to_le_bytes())?;
+        return Ok(4);
+    }
+
+    fn write_char_utf32_be(&mut self, value: char) -> io::Result<usize> {
+        self.write_all(&(value as u32).to_be_bytes())?;
+        return Ok(4);
+    }
+
+    fn write_char_java_modified_utf8(&mut self, value: char) -> io::Result<usize> {
+        let mut buf = [0u8; 4];
+        return self.write_java_modified_utf8(value.encode_utf8(&mut buf));
+    }
 }
 
 mod private {
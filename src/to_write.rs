@@ -12,6 +12,36 @@ use std::io::Write;
 ///
 pub trait ToWrite {
     fn copy_to_write(&self, writer:  &mut dyn Write) -> io::Result<()>;
+
+    ///
+    /// Generic variant of copy_to_write used by ToVec.
+    /// Calls copy_to_write by default, implementations of ToWriter call copy_to_writer instead.
+    ///
+    fn copy_to_write_generic<W: Write + ?Sized>(&self, mut writer: &mut W) -> io::Result<()> where Self: Sized {
+        return self.copy_to_write(&mut writer);
+    }
+}
+
+///
+/// Generic variant of ToWrite for performance sensitive types.
+///
+/// copy_to_write calls every method of the writer through a vtable, implementing this trait instead allows
+/// the compiler to generate a specialized copy for each writer type. Unlike reading, this is rarely measurable,
+/// as writing to a Vec is dominated by appending to it rather than by the call.
+/// ToWrite is automatically implemented for all implementations and ToVec calls copy_to_writer with the Vec.
+///
+pub trait ToWriter {
+    fn copy_to_writer<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+impl <T> ToWrite for T where T: ToWriter {
+    fn copy_to_write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        return self.copy_to_writer(writer);
+    }
+
+    fn copy_to_write_generic<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        return self.copy_to_writer(writer);
+    }
 }
 
 ///
@@ -28,7 +58,7 @@ pub trait ToVec: private::Sealed {
 impl <T> ToVec for T where T: ToWrite {
     fn copy_to_vec(&self) -> io::Result<Vec<u8>> {
         let mut data = Vec::with_capacity(1024);
-        self.copy_to_write(&mut data)?;
+        self.copy_to_write_generic(&mut data)?;
         return Ok(data);
     }
 }
@@ -0,0 +1,292 @@
+use std::io;
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Write};
+use crate::string_read::StringRead;
+use crate::string_write::StringWrite;
+
+///
+/// Encodings supported by TranscodeReader and TranscodeWriter.
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TranscodeEncoding {
+    Utf16LE,
+    Utf16BE,
+    Utf32LE,
+    Utf32BE,
+
+    ///
+    /// ISO-8859-1, each byte is the unicode code point with the same value.
+    ///
+    Latin1,
+
+    ///
+    /// Java's modified utf-8 as used by java.io.DataInput#readUTF without the length prefix.
+    ///
+    JavaModifiedUtf8,
+}
+
+const OUTPUT_CAPACITY: usize = 8192;
+
+///
+/// Read adapter that decodes the data of the wrapped reader from the given encoding and provides it as utf-8.
+///
+/// The data is decoded incrementally, which allows processing arbitrarily large inputs with constant memory.
+/// Invalid data causes an InvalidData error once all data before it has been read.
+/// The wrapped reader is buffered internally and does not need to be a BufRead.
+///
+pub struct TranscodeReader<R: Read> {
+    inner: BufReader<R>,
+    encoding: TranscodeEncoding,
+    output: Vec<u8>,
+    position: usize,
+    error: Option<Error>,
+}
+
+impl <R: Read> TranscodeReader<R> {
+
+    pub fn new(inner: R, encoding: TranscodeEncoding) -> TranscodeReader<R> {
+        return TranscodeReader {
+            inner: BufReader::new(inner),
+            encoding,
+            output: Vec::with_capacity(OUTPUT_CAPACITY),
+            position: 0,
+            error: None,
+        };
+    }
+
+    pub fn encoding(&self) -> TranscodeEncoding {
+        return self.encoding;
+    }
+
+    ///
+    /// Returns the wrapped reader. Data that was already buffered but not yet decoded is lost.
+    ///
+    pub fn into_inner(self) -> R {
+        return self.inner.into_inner();
+    }
+
+    fn read_char(&mut self) -> io::Result<char> {
+        return match self.encoding {
+            TranscodeEncoding::Utf16LE => self.inner.read_char_utf16_le(),
+            TranscodeEncoding::Utf16BE => self.inner.read_char_utf16_be(),
+            TranscodeEncoding::Utf32LE => self.inner.read_char_utf32_le(),
+            TranscodeEncoding::Utf32BE => self.inner.read_char_utf32_be(),
+            TranscodeEncoding::Latin1 => {
+                let mut buf = [0u8];
+                self.inner.read_exact(&mut buf)?;
+                Ok(buf[0] as char)
+            }
+            TranscodeEncoding::JavaModifiedUtf8 => self.inner.read_char_java_modified_utf8(),
+        };
+    }
+
+    ///
+    /// Decodes chars into the output buffer until it is full or no more data is buffered in the wrapped reader.
+    /// Only blocks if nothing was decoded yet or if a char is split across reads of the wrapped reader.
+    ///
+    fn decode(&mut self) -> io::Result<()> {
+        self.output.clear();
+        self.position = 0;
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let mut buf = [0u8; 4];
+        while self.output.len() + 4 <= OUTPUT_CAPACITY {
+            if self.output.is_empty() {
+                if self.inner.fill_buf()?.is_empty() {
+                    return Ok(());
+                }
+            } else if self.inner.buffer().is_empty() {
+                return Ok(());
+            }
+
+            match self.read_char() {
+                Ok(c) => self.output.extend_from_slice(c.encode_utf8(&mut buf).as_bytes()),
+                Err(err) if self.output.is_empty() => return Err(err),
+                Err(err) => {
+                    self.error = Some(err);
+                    return Ok(());
+                }
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+impl <R: Read> Read for TranscodeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.consume(count);
+        return Ok(count);
+    }
+}
+
+impl <R: Read> BufRead for TranscodeReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.position >= self.output.len() {
+            self.decode()?;
+        }
+
+        return Ok(&self.output[self.position..]);
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.position = (self.position + amt).min(self.output.len());
+    }
+}
+
+///
+/// Write adapter that accepts utf-8 data and writes it to the wrapped writer in the given encoding.
+///
+/// Chars may be split across multiple calls to write, incomplete chars are kept until they are completed.
+/// Invalid utf-8 causes an InvalidData error once all data before it has been written.
+/// Call finish to detect data that ends in the middle of a char.
+///
+pub struct TranscodeWriter<W: Write> {
+    inner: W,
+    encoding: TranscodeEncoding,
+    pending: Vec<u8>,
+    error: Option<Error>,
+}
+
+fn utf8_sequence_length(lead: u8) -> usize {
+    return match lead {
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => 1
+    };
+}
+
+fn encode_latin1(data: &mut Vec<u8>, string: &str) -> io::Result<usize> {
+    for c in string.chars() {
+        if c as u32 > 0xFF {
+            return Err(Error::new(ErrorKind::InvalidInput, "char cannot be represented in latin-1"));
+        }
+        data.push(c as u8);
+    }
+
+    return Ok(data.len());
+}
+
+fn invalid_utf8() -> Error {
+    return Error::new(ErrorKind::InvalidData, "invalid utf-8 data");
+}
+
+impl <W: Write> TranscodeWriter<W> {
+
+    pub fn new(inner: W, encoding: TranscodeEncoding) -> TranscodeWriter<W> {
+        return TranscodeWriter { inner, encoding, pending: Vec::with_capacity(4), error: None };
+    }
+
+    pub fn encoding(&self) -> TranscodeEncoding {
+        return self.encoding;
+    }
+
+    ///
+    /// Flushes the wrapped writer and returns it.
+    /// Fails with InvalidData if the written data ended in the middle of a char.
+    ///
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        if !self.pending.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidData, "utf-8 data ends in the middle of a char"));
+        }
+
+        self.inner.flush()?;
+        return Ok(self.inner);
+    }
+
+    fn encode(&mut self, string: &str) -> io::Result<()> {
+        if string.is_empty() {
+            return Ok(());
+        }
+
+        let mut data: Vec<u8> = Vec::with_capacity(string.len() * 2);
+        let written = match self.encoding {
+            TranscodeEncoding::Utf16LE => data.write_string_utf16_le(string),
+            TranscodeEncoding::Utf16BE => data.write_string_utf16_be(string),
+            TranscodeEncoding::Utf32LE => data.write_string_utf32_le(string),
+            TranscodeEncoding::Utf32BE => data.write_string_utf32_be(string),
+            TranscodeEncoding::JavaModifiedUtf8 => data.write_java_modified_utf8(string),
+            TranscodeEncoding::Latin1 => encode_latin1(&mut data, string),
+        };
+
+        written?;
+        return self.inner.write_all(data.as_slice());
+    }
+}
+
+impl <W: Write> Write for TranscodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        let mut consumed = 0;
+        if !self.pending.is_empty() {
+            let needed = utf8_sequence_length(self.pending[0]) - self.pending.len();
+            consumed = needed.min(buf.len());
+            if consumed < needed {
+                self.pending.extend_from_slice(&buf[..consumed]);
+                return Ok(consumed);
+            }
+
+            //The pending bytes are only dropped once the completed char was encoded.
+            let mut sequence = self.pending.clone();
+            sequence.extend_from_slice(&buf[..consumed]);
+            match std::str::from_utf8(sequence.as_slice()) {
+                Ok(string) => self.encode(string)?,
+                Err(_) => {
+                    //The bytes of buf were already taken, the error is reported by the next call.
+                    self.pending.clear();
+                    self.error = Some(invalid_utf8());
+                    return Ok(consumed);
+                }
+            }
+
+            self.pending.clear();
+        }
+
+        let rest = &buf[consumed..];
+        let (valid, invalid) = match std::str::from_utf8(rest) {
+            Ok(_) => (rest.len(), false),
+            Err(err) => (err.valid_up_to(), err.error_len().is_some()),
+        };
+
+        if let Err(err) = self.encode(std::str::from_utf8(&rest[..valid]).unwrap()) {
+            if consumed == 0 {
+                return Err(err);
+            }
+
+            //The completed pending char was written, the error is reported by the next call.
+            return Ok(consumed);
+        }
+
+        if invalid {
+            if consumed + valid == 0 {
+                return Err(invalid_utf8());
+            }
+
+            //The error is reported by the next call that starts with the invalid data.
+            return Ok(consumed + valid);
+        }
+
+        self.pending.extend_from_slice(&rest[valid..]);
+        return Ok(buf.len());
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(err) = self.error.take() {
+            return Err(err);
+        }
+
+        return self.inner.flush();
+    }
+}
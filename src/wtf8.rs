@@ -0,0 +1,282 @@
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::io::Error;
+use std::{fmt, io};
+
+///
+/// Owned buffer of WTF-8 (wobbly transformation format) data.
+///
+/// WTF-8 is a superset of utf-8 that can also represent unpaired surrogates.
+/// This allows arbitrary utf-16 data, such as windows file names, to be stored without loss.
+/// Valid utf-16 data produces the same bytes as the equivalent utf-8 string.
+///
+#[derive(Debug, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Wtf8Buf {
+    bytes: Vec<u8>,
+}
+
+fn is_high_surrogate(cp: u32) -> bool {
+    return (0xD800..=0xDBFF).contains(&cp);
+}
+
+fn is_low_surrogate(cp: u32) -> bool {
+    return (0xDC00..=0xDFFF).contains(&cp);
+}
+
+fn invalid_wtf8() -> Error {
+    return crate::error::Error::new(crate::error::ErrorKind::InvalidEncoding, "invalid wtf-8 data").into();
+}
+
+///
+/// Decodes the code point at the start of data.
+/// Returns the code point and its size in bytes or None if the data does not start with a valid sequence.
+///
+fn decode_code_point(data: &[u8]) -> Option<(u32, usize)> {
+    let first = *data.first()? as u32;
+    let (len, min, mut cp) = match first {
+        0x00..=0x7F => return Some((first, 1)),
+        0xC2..=0xDF => (2, 0x80, first & 0x1F),
+        0xE0..=0xEF => (3, 0x800, first & 0x0F),
+        0xF0..=0xF4 => (4, 0x10000, first & 0x07),
+        _ => return None
+    };
+
+    if data.len() < len {
+        return None;
+    }
+
+    for b in &data[1..len] {
+        if b & 0xC0 != 0x80 {
+            return None;
+        }
+
+        cp = (cp << 6) | (*b as u32 & 0x3F);
+    }
+
+    if cp < min || cp > 0x10FFFF {
+        return None;
+    }
+
+    return Some((cp, len));
+}
+
+impl Wtf8Buf {
+
+    ///
+    /// Creates an empty buffer.
+    ///
+    pub fn new() -> Wtf8Buf {
+        return Wtf8Buf { bytes: Vec::new() };
+    }
+
+    ///
+    /// Creates a buffer from utf-16 data.
+    /// Surrogate pairs are combined, unpaired surrogates are preserved. This never fails.
+    ///
+    pub fn from_utf16(data: &[u16]) -> Wtf8Buf {
+        let mut result = Wtf8Buf { bytes: Vec::with_capacity(data.len()) };
+        let mut index = 0usize;
+        while index < data.len() {
+            let cur = data[index] as u32;
+            index += 1;
+            if is_high_surrogate(cur) && index < data.len() && is_low_surrogate(data[index] as u32) {
+                let low = data[index] as u32;
+                index += 1;
+                result.push_code_point(0x10000 + ((cur - 0xD800) << 10) + (low - 0xDC00));
+                continue;
+            }
+
+            result.push_code_point(cur);
+        }
+
+        return result;
+    }
+
+    ///
+    /// Creates a buffer from bytes that are already WTF-8 encoded.
+    /// Fails if the bytes are not valid WTF-8.
+    /// This is also the case if they contain a surrogate pair encoded as two separate surrogates.
+    ///
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Wtf8Buf> {
+        let mut index = 0usize;
+        let mut last_high = false;
+        while index < bytes.len() {
+            let (cp, len) = decode_code_point(&bytes[index..]).ok_or_else(invalid_wtf8)?;
+            if last_high && is_low_surrogate(cp) {
+                return Err(invalid_wtf8());
+            }
+
+            last_high = is_high_surrogate(cp);
+            index += len;
+        }
+
+        return Ok(Wtf8Buf { bytes });
+    }
+
+    ///
+    /// Appends a char.
+    ///
+    pub fn push_char(&mut self, c: char) {
+        self.push_code_point(c as u32);
+    }
+
+    ///
+    /// Appends a string.
+    ///
+    pub fn push_str(&mut self, string: &str) {
+        self.bytes.extend_from_slice(string.as_bytes());
+    }
+
+    fn push_code_point(&mut self, cp: u32) {
+        if cp < 0x80 {
+            self.bytes.push(cp as u8);
+        } else if cp < 0x800 {
+            self.bytes.push((0xC0 | (cp >> 6)) as u8);
+            self.bytes.push((0x80 | (cp & 0x3F)) as u8);
+        } else if cp < 0x10000 {
+            self.bytes.push((0xE0 | (cp >> 12)) as u8);
+            self.bytes.push((0x80 | ((cp >> 6) & 0x3F)) as u8);
+            self.bytes.push((0x80 | (cp & 0x3F)) as u8);
+        } else {
+            self.bytes.push((0xF0 | (cp >> 18)) as u8);
+            self.bytes.push((0x80 | ((cp >> 12) & 0x3F)) as u8);
+            self.bytes.push((0x80 | ((cp >> 6) & 0x3F)) as u8);
+            self.bytes.push((0x80 | (cp & 0x3F)) as u8);
+        }
+    }
+
+    ///
+    /// Returns an iterator over all code points. Unpaired surrogates are returned as is.
+    ///
+    pub fn code_points(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut index = 0usize;
+        return std::iter::from_fn(move || {
+            let (cp, len) = decode_code_point(&self.bytes[index..])?;
+            index += len;
+            return Some(cp);
+        });
+    }
+
+    ///
+    /// Encodes the content as utf-16. This is the exact inverse of from_utf16.
+    ///
+    pub fn to_utf16(&self) -> Vec<u16> {
+        let mut result = Vec::with_capacity(self.bytes.len());
+        for cp in self.code_points() {
+            if cp >= 0x10000 {
+                let v = cp - 0x10000;
+                result.push((0xD800 | (v >> 10)) as u16);
+                result.push((0xDC00 | (v & 0x3FF)) as u16);
+                continue;
+            }
+
+            result.push(cp as u16);
+        }
+
+        return result;
+    }
+
+    ///
+    /// Returns true if the buffer contains unpaired surrogates and therefore is not valid utf-8.
+    ///
+    pub fn has_surrogates(&self) -> bool {
+        return self.as_str().is_none();
+    }
+
+    ///
+    /// Returns the content as str if it does not contain unpaired surrogates.
+    ///
+    pub fn as_str(&self) -> Option<&str> {
+        return std::str::from_utf8(self.bytes.as_slice()).ok();
+    }
+
+    ///
+    /// Converts the buffer into a String if it does not contain unpaired surrogates.
+    /// Returns the unchanged buffer otherwise.
+    ///
+    pub fn into_string(self) -> Result<String, Wtf8Buf> {
+        return String::from_utf8(self.bytes).map_err(|e| Wtf8Buf { bytes: e.into_bytes() });
+    }
+
+    ///
+    /// Converts the buffer into a String, unpaired surrogates are replaced with U+FFFD.
+    ///
+    pub fn to_string_lossy(&self) -> String {
+        return self.code_points().map(|cp| char::from_u32(cp).unwrap_or(char::REPLACEMENT_CHARACTER)).collect();
+    }
+
+    ///
+    /// Returns the WTF-8 encoded bytes.
+    ///
+    pub fn as_bytes(&self) -> &[u8] {
+        return self.bytes.as_slice();
+    }
+
+    ///
+    /// Returns the WTF-8 encoded bytes.
+    ///
+    pub fn into_bytes(self) -> Vec<u8> {
+        return self.bytes;
+    }
+
+    ///
+    /// Converts the buffer into an OsString.
+    /// On unix the WTF-8 bytes are used as is, on windows the buffer is converted to utf-16.
+    /// Both conversions are lossless.
+    ///
+    #[cfg(unix)]
+    pub fn into_os_string(self) -> OsString {
+        use std::os::unix::ffi::OsStringExt;
+        return OsString::from_vec(self.bytes);
+    }
+
+    ///
+    /// Converts the buffer into an OsString.
+    /// On unix the WTF-8 bytes are used as is, on windows the buffer is converted to utf-16.
+    /// Both conversions are lossless.
+    ///
+    #[cfg(windows)]
+    pub fn into_os_string(self) -> OsString {
+        use std::os::windows::ffi::OsStringExt;
+        return OsString::from_wide(self.to_utf16().as_slice());
+    }
+
+    ///
+    /// Creates a buffer from an OsString.
+    /// On unix this fails if the bytes of the OsString are not valid WTF-8.
+    ///
+    #[cfg(unix)]
+    pub fn from_os_string(string: OsString) -> io::Result<Wtf8Buf> {
+        use std::os::unix::ffi::OsStringExt;
+        return Wtf8Buf::from_bytes(string.into_vec());
+    }
+
+    ///
+    /// Creates a buffer from an OsString.
+    /// On unix this fails if the bytes of the OsString are not valid WTF-8.
+    ///
+    #[cfg(windows)]
+    pub fn from_os_string(string: OsString) -> io::Result<Wtf8Buf> {
+        use std::os::windows::ffi::OsStrExt;
+        let wide: Vec<u16> = string.encode_wide().collect();
+        return Ok(Wtf8Buf::from_utf16(wide.as_slice()));
+    }
+}
+
+impl From<&str> for Wtf8Buf {
+    fn from(string: &str) -> Self {
+        return Wtf8Buf { bytes: string.as_bytes().to_vec() };
+    }
+}
+
+impl From<String> for Wtf8Buf {
+    fn from(string: String) -> Self {
+        return Wtf8Buf { bytes: string.into_bytes() };
+    }
+}
+
+impl Display for Wtf8Buf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        return f.write_str(self.to_string_lossy().as_str());
+    }
+}
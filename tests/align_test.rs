@@ -0,0 +1,52 @@
+use std::io;
+use std::io::{Cursor, ErrorKind};
+use rw_utils::align::{AlignRead, AlignWrite};
+use rw_utils::counting::{CountingReader, CountingWriter};
+use rw_utils::num_read::NumRead;
+use rw_utils::num_write::NumWrite;
+
+#[test]
+fn test_write_padding() -> io::Result<()> {
+    let mut writer = CountingWriter::new(Vec::new());
+    assert_eq!(writer.write_padding_to_alignment(4, 0)?, 0);
+    writer.write_u8(1)?;
+    assert_eq!(writer.write_padding_to_alignment(4, 0)?, 3);
+    writer.write_u16_le(2)?;
+    assert_eq!(writer.write_padding_to_alignment(8, 0xFF)?, 2);
+    writer.write_u8(3)?;
+    assert_eq!(writer.write_padding_to_alignment(3, 0xAA)?, 0);
+    assert_eq!(writer.write_padding_to_alignment(200, 0)?, 191);
+    assert_eq!(writer.position(), 200);
+
+    let data = writer.into_inner();
+    assert_eq!(&data[..9], &[1, 0, 0, 0, 2, 0, 0xFF, 0xFF, 3]);
+    assert!(data[9..].iter().all(|b| *b == 0));
+
+    let mut writer = CountingWriter::new(Vec::new());
+    assert_eq!(writer.write_padding_to_alignment(0, 0).unwrap_err().kind(), ErrorKind::InvalidInput);
+    return Ok(());
+}
+
+#[test]
+fn test_read_padding() -> io::Result<()> {
+    let data = [1u8, 0, 0, 0, 2, 0, 0xFF, 0xFF, 3];
+    let mut reader = CountingReader::new(&data[..]);
+    assert_eq!(reader.read_u8()?, 1);
+    assert_eq!(reader.expect_padding(4, 0)?, 3);
+    assert_eq!(reader.read_u16_le()?, 2);
+    assert_eq!(reader.skip_to_alignment(8)?, 2);
+    assert_eq!(reader.read_u8()?, 3);
+    assert_eq!(reader.skip_to_alignment(16).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let mut cursor = Cursor::new(&data[..]);
+    cursor.set_position(5);
+    let err = cursor.expect_padding(8, 0).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::InvalidPadding);
+    assert_eq!(err.offset(), Some(6));
+
+    cursor.set_position(6);
+    assert_eq!(cursor.expect_padding(8, 0xFF)?, 2);
+    return Ok(());
+}
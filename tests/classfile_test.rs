@@ -0,0 +1,111 @@
+use std::io;
+use std::io::ErrorKind;
+use rw_utils::classfile::*;
+use rw_utils::from_read::DefaultFromRead;
+use rw_utils::to_write::{ToVec, ToWrite};
+
+//Taken from javac -g:none --release 8 of:
+//@Deprecated public class Sample implements Runnable {
+//    static final long BIG = 1234567890123L; double d = 2.5; String s = "hé\u0000";
+//    public void run() { d += BIG; }
+//}
+const SAMPLE: [u8; 438] = [202, 254, 186, 190, 0, 0, 0, 52, 0, 35, 10, 0, 2, 0, 3, 7, 0, 4, 12, 0,
+    5, 0, 6, 1, 0, 16, 106, 97, 118, 97, 47, 108, 97, 110, 103, 47, 79, 98, 106, 101,
+    99, 116, 1, 0, 6, 60, 105, 110, 105, 116, 62, 1, 0, 3, 40, 41, 86, 6, 64, 4,
+    0, 0, 0, 0, 0, 0, 9, 0, 10, 0, 11, 7, 0, 12, 12, 0, 13, 0, 14, 1,
+    0, 6, 83, 97, 109, 112, 108, 101, 1, 0, 1, 100, 1, 0, 1, 68, 8, 0, 16, 1,
+    0, 5, 104, 195, 169, 192, 128, 9, 0, 10, 0, 18, 12, 0, 19, 0, 20, 1, 0, 1,
+    115, 1, 0, 18, 76, 106, 97, 118, 97, 47, 108, 97, 110, 103, 47, 83, 116, 114, 105, 110,
+    103, 59, 6, 66, 113, 247, 31, 176, 76, 176, 0, 7, 0, 24, 1, 0, 18, 106, 97, 118,
+    97, 47, 108, 97, 110, 103, 47, 82, 117, 110, 110, 97, 98, 108, 101, 1, 0, 3, 66, 73,
+    71, 1, 0, 1, 74, 1, 0, 13, 67, 111, 110, 115, 116, 97, 110, 116, 86, 97, 108, 117,
+    101, 5, 0, 0, 1, 31, 113, 251, 4, 203, 1, 0, 4, 67, 111, 100, 101, 1, 0, 3,
+    114, 117, 110, 1, 0, 10, 68, 101, 112, 114, 101, 99, 97, 116, 101, 100, 1, 0, 25, 82,
+    117, 110, 116, 105, 109, 101, 86, 105, 115, 105, 98, 108, 101, 65, 110, 110, 111, 116, 97, 116,
+    105, 111, 110, 115, 1, 0, 22, 76, 106, 97, 118, 97, 47, 108, 97, 110, 103, 47, 68, 101,
+    112, 114, 101, 99, 97, 116, 101, 100, 59, 0, 33, 0, 10, 0, 2, 0, 1, 0, 23, 0,
+    3, 0, 24, 0, 25, 0, 26, 0, 1, 0, 27, 0, 0, 0, 2, 0, 28, 0, 0, 0,
+    13, 0, 14, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 2, 0, 1, 0, 5, 0,
+    6, 0, 1, 0, 30, 0, 0, 0, 30, 0, 3, 0, 1, 0, 0, 0, 18, 42, 183, 0,
+    1, 42, 20, 0, 7, 181, 0, 9, 42, 18, 15, 181, 0, 17, 177, 0, 0, 0, 0, 0,
+    1, 0, 31, 0, 6, 0, 1, 0, 30, 0, 0, 0, 25, 0, 5, 0, 1, 0, 0, 0,
+    13, 42, 89, 180, 0, 9, 20, 0, 21, 99, 181, 0, 9, 177, 0, 0, 0, 0, 0, 2,
+    0, 32, 0, 0, 0, 0, 0, 33, 0, 0, 0, 6, 0, 1, 0, 34, 0, 0];
+
+#[test]
+fn test_read_class() -> io::Result<()> {
+    let class = ClassFile::from_slice(&SAMPLE)?;
+    assert_eq!(class.major_version, 52);
+    assert_eq!(class.minor_version, 0);
+    assert_eq!(class.access_flags, 0x21);
+    assert_eq!(class.this_class_name()?, "Sample");
+    assert_eq!(class.super_class_name()?, Some("java/lang/Object".to_string()));
+    assert_eq!(class.interfaces.len(), 1);
+    assert_eq!(class.class_name(class.interfaces[0])?, "java/lang/Runnable");
+
+    assert_eq!(class.constant_pool.len(), 35);
+    assert_eq!(class.constant_pool[0], Constant::Unusable);
+    assert_eq!(class.constant_pool[7], Constant::Double(2.5));
+    assert_eq!(class.constant_pool[8], Constant::Unusable);
+    assert_eq!(class.constant_pool[28], Constant::Long(1234567890123));
+    assert_eq!(class.constant(29), None);
+    assert_eq!(class.constant(35), None);
+    assert_eq!(class.constant(1), Some(&Constant::MethodRef { class_index: 2, name_and_type_index: 3 }));
+    assert_eq!(class.constant(16), Some(&Constant::Utf8(vec![104, 195, 169, 192, 128])));
+    assert_eq!(class.utf8(16)?, "hé\u{0}");
+    assert_eq!(class.utf8(15).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let fields: Vec<String> = class.fields.iter().map(|f| class.utf8(f.name_index)).collect::<io::Result<_>>()?;
+    assert_eq!(fields, vec!["BIG", "d", "s"]);
+    let methods: Vec<String> = class.methods.iter().map(|m| class.utf8(m.name_index)).collect::<io::Result<_>>()?;
+    assert_eq!(methods, vec!["<init>", "run"]);
+
+    let constant_value = class.find_attribute(class.fields[0].attributes.as_slice(), "ConstantValue").unwrap();
+    assert_eq!(constant_value.info, vec![0, 28]);
+    let code = class.find_attribute(class.methods[1].attributes.as_slice(), "Code").unwrap();
+    assert_eq!(code.info.len(), 25);
+    assert!(class.find_attribute(class.attributes.as_slice(), "Deprecated").is_some());
+    assert!(class.find_attribute(class.attributes.as_slice(), "RuntimeVisibleAnnotations").is_some());
+    assert!(class.find_attribute(class.attributes.as_slice(), "SourceFile").is_none());
+    return Ok(());
+}
+
+#[test]
+fn test_rewrite_class() -> io::Result<()> {
+    let class = ClassFile::from_slice(&SAMPLE)?;
+    assert_eq!(class.copy_to_vec()?, SAMPLE.to_vec());
+
+    let mut broken = class.clone();
+    broken.constant_pool.remove(8);
+    assert_eq!(broken.copy_to_vec().unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    let mut oversized = class.clone();
+    oversized.constant_pool.resize(u16::MAX as usize + 1, Constant::Integer(0));
+    let mut out = Vec::new();
+    assert_eq!(oversized.copy_to_write(&mut out).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert!(out.is_empty());
+    return Ok(());
+}
+
+#[test]
+fn test_invalid_class() {
+    assert_eq!(ClassFile::from_slice(&[0xCA, 0xFE, 0xBA, 0xBF, 0, 0, 0, 52]).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(ClassFile::from_slice(&[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 2, 2]).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(ClassFile::from_slice(&[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 2, 5, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(ClassFile::from_slice(&SAMPLE[..400]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_error_offset() {
+    let err = rw_utils::Error::from(ClassFile::from_slice(&[0xCA, 0xFE, 0xBA, 0xBF, 0, 0, 0, 52]).unwrap_err());
+    assert_eq!(err.offset(), Some(4));
+
+    //Invalid constant pool tag at index 10
+    let err = rw_utils::Error::from(ClassFile::from_slice(&[0xCA, 0xFE, 0xBA, 0xBE, 0, 0, 0, 52, 0, 2, 2]).unwrap_err());
+    assert_eq!(err.offset(), Some(11));
+    assert_eq!(err.field_path(), "constant_pool");
+
+    let err = rw_utils::Error::from(ClassFile::from_slice(&SAMPLE[..400]).unwrap_err());
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::UnexpectedEof);
+    assert_eq!(err.offset(), Some(400));
+}
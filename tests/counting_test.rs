@@ -0,0 +1,66 @@
+use std::io;
+use std::io::{BufRead, BufReader, Cursor, Read};
+use rw_utils::counting::{CountingReader, CountingWriter};
+use rw_utils::leb128_read::Leb128Read;
+use rw_utils::leb128_write::Leb128Write;
+use rw_utils::num_read::NumRead;
+use rw_utils::num_write::NumWrite;
+use rw_utils::string_read::StringRead;
+use rw_utils::string_write::StringWrite;
+
+#[test]
+fn test_counting_writer() -> io::Result<()> {
+    let mut writer = CountingWriter::new(Vec::new());
+    writer.write_u32_le(1)?;
+    assert_eq!(writer.position(), 4);
+    writer.write_leb128_u32(300)?;
+    assert_eq!(writer.position(), 6);
+    writer.write_string_u16_be_len_utf8("abc")?;
+    assert_eq!(writer.position(), 11);
+
+    writer.reset_count();
+    writer.write_u8(0)?;
+    assert_eq!(writer.position(), 1);
+    assert_eq!(writer.get_ref().len(), 12);
+    assert_eq!(writer.into_inner().len(), 12);
+    return Ok(());
+}
+
+#[test]
+fn test_counting_reader() -> io::Result<()> {
+    let mut data: Vec<u8> = vec![];
+    data.write_u32_le(1)?;
+    data.write_leb128_u32(300)?;
+    data.write_string_u16_be_len_utf8("abc")?;
+
+    let mut reader = CountingReader::new(Cursor::new(&data));
+    assert_eq!(reader.read_u32_le()?, 1);
+    assert_eq!(reader.position(), 4);
+    assert_eq!(reader.read_leb128_u32()?, 300);
+    assert_eq!(reader.position(), 6);
+    reader.reset_count();
+    assert_eq!(reader.read_string_u16_be_len_utf8()?, "abc");
+    assert_eq!(reader.position(), 5);
+    assert_eq!(reader.read_u8().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(reader.position(), 5);
+    assert_eq!(reader.into_inner().position(), 11);
+    return Ok(());
+}
+
+#[test]
+fn test_counting_buf_reader() -> io::Result<()> {
+    let mut reader = CountingReader::new(BufReader::new(&b"one\ntwo\nthree"[..]));
+    assert_eq!(reader.fill_buf()?.len(), 13);
+    assert_eq!(reader.position(), 0);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    assert_eq!(line, "one\n");
+    assert_eq!(reader.position(), 4);
+
+    let mut rest = String::new();
+    reader.read_to_string(&mut rest)?;
+    assert_eq!(rest, "two\nthree");
+    assert_eq!(reader.position(), 13);
+    return Ok(());
+}
@@ -0,0 +1,63 @@
+use std::io;
+use std::io::{Cursor, ErrorKind, Read};
+use rw_utils::delimited::DelimitedRead;
+use rw_utils::from_read::FromRead;
+use rw_utils::num_read::NumRead;
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Record {
+    a: u16,
+    b: u16,
+}
+
+impl FromRead for Record {
+    fn copy_from_read(&mut self, mut reader: &mut dyn Read) -> io::Result<()> {
+        self.a = reader.read_u16_le()?;
+        self.b = reader.read_u16_le()?;
+        return Ok(());
+    }
+}
+
+const DATA: [u8; 12] = [4, 1, 0, 2, 0, 6, 3, 0, 4, 0, 9, 9];
+
+#[test]
+fn test_read_delimited() -> io::Result<()> {
+    let mut cursor = Cursor::new(&DATA[..]);
+    let length = cursor.read_u8()? as u64;
+    let mut record = Record::default();
+    cursor.read_delimited(length, |reader| record.copy_from_read(reader))?;
+    assert_eq!(record, Record { a: 1, b: 2 });
+
+    let length = cursor.read_u8()? as u64;
+    let err = cursor.read_delimited(length, |reader| record.copy_from_read(reader)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::TrailingData);
+    assert_eq!(err.trailing_bytes(), Some(2));
+
+    //The body cannot read past the region.
+    let mut cursor = Cursor::new(&DATA[..]);
+    let err = cursor.read_delimited(3, |mut reader| reader.read_u32_le()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+
+    //The region is longer than the data.
+    let mut cursor = Cursor::new(&DATA[..]);
+    let err = cursor.read_delimited(20, |reader| reader.read_to_end(&mut Vec::new())).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_read_delimited_skip_trailing() -> io::Result<()> {
+    let mut cursor = Cursor::new(&DATA[5..]);
+    let length = cursor.read_u8()? as u64;
+    let mut record = Record::default();
+    cursor.read_delimited_skip_trailing(length, |reader| record.copy_from_read(reader))?;
+    assert_eq!(record, Record { a: 3, b: 4 });
+    assert_eq!(cursor.position(), 7);
+
+    let mut cursor = Cursor::new(&DATA[..]);
+    let err = cursor.read_delimited_skip_trailing(20, |mut reader| reader.read_u8()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
@@ -0,0 +1,175 @@
+use std::io;
+use std::io::ErrorKind;
+use rw_utils::dex::*;
+use rw_utils::from_read::DefaultFromRead;
+use rw_utils::leb128_write::Leb128Write;
+use rw_utils::num_write::NumWrite;
+use rw_utils::string_write::StringWrite;
+
+const STRINGS: [&str; 11] = ["<init>", "I", "LFoo;", "Ljava/lang/Object;", "V", "VI", "count", "setCount", "value", "Foo.java", "é\u{1F600}"];
+
+fn patch_u32(data: &mut Vec<u8>, offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+}
+
+///
+/// Writes the current end of data as u32 offset at the given offset.
+///
+fn link(data: &mut Vec<u8>, offset: usize) {
+    let here = data.len() as u32;
+    patch_u32(data, offset, here);
+}
+
+fn align(data: &mut Vec<u8>) {
+    while data.len() % 4 != 0 {
+        data.push(0);
+    }
+}
+
+///
+/// Builds a dex file equivalent to:
+/// class Foo { int count; void setCount(int value) { this.count = value; } abstract void value(int p); }
+///
+fn build_dex() -> io::Result<Vec<u8>> {
+    let mut data: Vec<u8> = vec![0; 0x70];
+    data[0..8].copy_from_slice(b"dex\n035\0");
+    patch_u32(&mut data, 36, 0x70);
+    patch_u32(&mut data, 40, ENDIAN_CONSTANT);
+
+    let string_ids_off = data.len();
+    data.resize(data.len() + STRINGS.len() * 4, 0);
+    let type_ids_off = data.len();
+    for descriptor in [1u32, 2, 3, 4].iter() {
+        data.write_u32_le(*descriptor)?;
+    }
+    let proto_ids_off = data.len();
+    data.write_u32_le(5)?;
+    data.write_u32_le(3)?;
+    data.write_u32_le(0)?;
+    let field_ids_off = data.len();
+    data.write_u16_le(1)?;
+    data.write_u16_le(0)?;
+    data.write_u32_le(6)?;
+    let method_ids_off = data.len();
+    for name in [7u32, 8].iter() {
+        data.write_u16_le(1)?;
+        data.write_u16_le(0)?;
+        data.write_u32_le(*name)?;
+    }
+    let class_defs_off = data.len();
+    for value in [1u32, 1, 2, 0, 9, 0, 0, 0].iter() {
+        data.write_u32_le(*value)?;
+    }
+
+    let data_off = data.len();
+    for (index, string) in STRINGS.iter().enumerate() {
+        link(&mut data, string_ids_off + index * 4);
+        data.write_leb128_u32(string.encode_utf16().count() as u32)?;
+        data.write_java_modified_utf8_zero_terminated(string)?;
+    }
+
+    align(&mut data);
+    link(&mut data, proto_ids_off + 8);
+    data.write_u32_le(1)?;
+    data.write_u16_le(0)?;
+
+    let debug_info_off = data.len();
+    data.write_leb128_u32(3)?;
+    data.write_leb128_u32(2)?;
+    data.write_leb128p1_u32(8)?;
+    data.write_leb128p1_u32(NO_INDEX)?;
+    data.write_u8(0)?;
+
+    align(&mut data);
+    let code_off = data.len();
+    for value in [2u16, 2, 0, 0].iter() {
+        data.write_u16_le(*value)?;
+    }
+    data.write_u32_le(debug_info_off as u32)?;
+    data.write_u32_le(3)?;
+    for insn in [0x0059u16, 0x0010, 0x000e].iter() {
+        data.write_u16_le(*insn)?;
+    }
+
+    link(&mut data, class_defs_off + 24);
+    for value in [0u32, 1, 0, 2, 0, 2, 0, 1, code_off as u32, 1, 0x401, 0].iter() {
+        data.write_leb128_u32(*value)?;
+    }
+
+    let header = [
+        (56, STRINGS.len(), string_ids_off), (64, 4, type_ids_off), (72, 1, proto_ids_off), (80, 1, field_ids_off),
+        (88, 2, method_ids_off), (96, 1, class_defs_off), (104, data.len() - data_off, data_off)
+    ];
+    for (offset, size, off) in header.iter() {
+        patch_u32(&mut data, *offset, *size as u32);
+        patch_u32(&mut data, offset + 4, *off as u32);
+    }
+    let file_size = data.len() as u32;
+    patch_u32(&mut data, 32, file_size);
+    return Ok(data);
+}
+
+#[test]
+fn test_read_dex() -> io::Result<()> {
+    let data = build_dex()?;
+    let dex = DexFile::from_vec(&data)?;
+    assert_eq!(dex, DexFile::parse(data.as_slice())?);
+    assert_eq!(&dex.header.magic, b"dex\n035\0");
+    assert_eq!(dex.header.file_size as usize, data.len());
+    assert_eq!(dex.strings, STRINGS.iter().map(|s| s.to_string()).collect::<Vec<String>>());
+    assert_eq!(dex.type_descriptor(1), Some("LFoo;"));
+    assert_eq!(dex.type_descriptor(4), None);
+    assert_eq!(dex.proto_ids, vec![ProtoId { shorty_idx: 5, return_type_idx: 3, parameters: vec![0] }]);
+    assert_eq!(dex.field_name(0), Some("count"));
+    assert_eq!(dex.method_name(0), Some("setCount"));
+    assert_eq!(dex.method_name(1), Some("value"));
+
+    assert_eq!(dex.class_defs.len(), 1);
+    let class_def = &dex.class_defs[0];
+    assert_eq!(dex.type_descriptor(class_def.class_idx), Some("LFoo;"));
+    assert_eq!(dex.type_descriptor(class_def.superclass_idx), Some("Ljava/lang/Object;"));
+    assert_eq!(dex.string(class_def.source_file_idx), Some("Foo.java"));
+    assert!(class_def.interfaces.is_empty());
+
+    let class_data = class_def.class_data.as_ref().unwrap();
+    assert!(class_data.static_fields.is_empty());
+    assert!(class_data.direct_methods.is_empty());
+    assert_eq!(class_data.instance_fields, vec![EncodedField { field_idx: 0, access_flags: 2 }]);
+    assert_eq!(class_data.virtual_methods.len(), 2);
+    assert_eq!(class_data.virtual_methods[1], EncodedMethod { method_idx: 1, access_flags: 0x401, code: None });
+
+    let code = class_data.virtual_methods[0].code.as_ref().unwrap();
+    assert_eq!(code.registers_size, 2);
+    assert_eq!(code.ins_size, 2);
+    assert_eq!(code.insns, vec![0x0059, 0x0010, 0x000e]);
+    assert_eq!(code.debug_info, Some(DebugInfo { line_start: 3, parameter_names: vec![8, NO_INDEX] }));
+    return Ok(());
+}
+
+#[test]
+fn test_invalid_dex() -> io::Result<()> {
+    let data = build_dex()?;
+    assert_eq!(DexFile::parse(&data[..0x60]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut broken = data.clone();
+    broken[0] = b'D';
+    assert_eq!(DexFile::parse(broken.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut broken = data.clone();
+    broken[40..44].copy_from_slice(&REVERSE_ENDIAN_CONSTANT.to_le_bytes());
+    assert_eq!(DexFile::parse(broken.as_slice()).unwrap_err().kind(), ErrorKind::Unsupported);
+
+    let mut broken = data.clone();
+    broken[56..60].copy_from_slice(&0x10000000u32.to_le_bytes());
+    assert_eq!(DexFile::parse(broken.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut broken = data.clone();
+    let last_string = u32::from_le_bytes([broken[152], broken[153], broken[154], broken[155]]) as usize;
+    broken[last_string] = 2;
+    let err = DexFile::parse(broken.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.field_path(), "strings");
+    assert_eq!(err.offset(), Some(last_string as u64));
+    return Ok(());
+}
@@ -0,0 +1,96 @@
+use std::io;
+use std::io::{Cursor, ErrorKind};
+use rw_utils::counting::Position;
+use rw_utils::dotnet_binary_read::DotNetBinaryRead;
+use rw_utils::dotnet_binary_write::DotNetBinaryWrite;
+use rw_utils::dotnet_types::DotNetDecimal;
+
+//What System.IO.BinaryWriter produces for:
+//Write(true); Write((short)-2); Write(0x12345678); Write(-1.5m); Write('€'); Write("héllo");
+//Write7BitEncodedInt(-1); Write7BitEncodedInt(300); Write7BitEncodedInt64(-1L); Write(0L);
+const PRIMITIVES: [u8; 58] = [1, 254, 255, 120, 86, 52, 18, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 128,
+    226, 130, 172, 6, 104, 195, 169, 108, 108, 111, 255, 255, 255, 255, 15, 172, 2,
+    255, 255, 255, 255, 255, 255, 255, 255, 255, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+
+#[test]
+fn test_write_primitives() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_dotnet_boolean(true)?;
+    vec.write_dotnet_int16(-2)?;
+    vec.write_dotnet_int32(0x12345678)?;
+    vec.write_dotnet_decimal(&DotNetDecimal::new(15, 1, true)?)?;
+    vec.write_dotnet_char('€')?;
+    vec.write_dotnet_string("héllo")?;
+    vec.write_dotnet_7bit_encoded_int(-1)?;
+    vec.write_dotnet_7bit_encoded_int(300)?;
+    vec.write_dotnet_7bit_encoded_int64(-1)?;
+    vec.write_dotnet_int64(0)?;
+    assert_eq!(vec, PRIMITIVES.to_vec());
+    return Ok(());
+}
+
+#[test]
+fn test_read_primitives() -> io::Result<()> {
+    let mut cursor = Cursor::new(&PRIMITIVES);
+    assert!(cursor.read_dotnet_boolean()?);
+    assert_eq!(cursor.read_dotnet_int16()?, -2);
+    assert_eq!(cursor.read_dotnet_int32()?, 0x12345678);
+    let decimal = cursor.read_dotnet_decimal()?;
+    assert_eq!(decimal.mantissa(), 15);
+    assert_eq!(decimal.scale(), 1);
+    assert!(decimal.is_negative());
+    assert_eq!(decimal.to_string(), "-1.5");
+    assert_eq!(cursor.read_dotnet_char()?, '€');
+    assert_eq!(cursor.read_dotnet_string()?, "héllo");
+    assert_eq!(cursor.read_dotnet_7bit_encoded_int()?, -1);
+    assert_eq!(cursor.read_dotnet_7bit_encoded_int()?, 300);
+    assert_eq!(cursor.read_dotnet_7bit_encoded_int64()?, -1);
+    assert_eq!(cursor.read_dotnet_int64()?, 0);
+    assert_eq!(cursor.read_dotnet_byte().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_7bit_overflow() {
+    assert_eq!(Cursor::new(&[255, 255, 255, 255, 16]).read_dotnet_7bit_encoded_int().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new(&[128, 128, 128, 128, 128, 128, 128, 128, 128, 2]).read_dotnet_7bit_encoded_int64().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new(&[128, 128, 128, 128, 8]).read_dotnet_7bit_encoded_int().unwrap(), i32::MIN);
+    assert_eq!(Cursor::new(&[128, 128, 128, 128, 128, 128, 128, 128, 128, 1]).read_dotnet_7bit_encoded_int64().unwrap(), i64::MIN);
+    //A negative length is rejected just like .NET does.
+    assert_eq!(Cursor::new(&[255, 255, 255, 255, 15]).read_dotnet_string().unwrap_err().kind(), ErrorKind::InvalidData);
+
+    //The offset is attached by a reader that knows its position.
+    let mut cursor = Cursor::new(vec![1, 255, 255, 255, 255, 16]);
+    cursor.read_dotnet_byte().unwrap();
+    let err = cursor.track_offset(|reader| reader.read_dotnet_7bit_encoded_int()).unwrap_err();
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::Overflow);
+    assert_eq!(err.offset(), Some(6));
+}
+
+#[test]
+fn test_char_and_string_edge_cases() -> io::Result<()> {
+    assert_eq!(Cursor::new(&[240, 159, 146, 169]).read_dotnet_char().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new(&[237, 160, 128]).read_dotnet_char().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new(&[0x80]).read_dotnet_char().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Vec::new().write_dotnet_char('\u{1F4A9}').unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(Cursor::new(&[3, 97, 0xFF, 98]).read_dotnet_string()?, "a\u{FFFD}b");
+    assert_eq!(Cursor::new(&[3, 97]).read_dotnet_string().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_decimal() -> io::Result<()> {
+    assert_eq!(DotNetDecimal::new(5, 3, false)?.to_string(), "0.005");
+    assert_eq!(DotNetDecimal::new(0, 2, true)?.to_string(), "0.00");
+    assert_eq!(DotNetDecimal::new((1u128 << 96) - 1, 0, false)?.to_string(), "79228162514264337593543950335");
+    assert_eq!(DotNetDecimal::new(1u128 << 96, 0, false).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(DotNetDecimal::new(1, 29, false).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    let invalid = DotNetDecimal { lo: 1, mid: 0, hi: 0, flags: 1 };
+    assert!(!invalid.is_valid());
+    assert_eq!(Vec::new().write_dotnet_decimal(&invalid).unwrap_err().kind(), ErrorKind::InvalidInput);
+    let data = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0];
+    assert_eq!(Cursor::new(&data).read_dotnet_decimal().unwrap_err().kind(), ErrorKind::InvalidData);
+    return Ok(());
+}
@@ -0,0 +1,83 @@
+use std::io;
+use std::io::Cursor;
+use rw_utils::classfile::{ClassFile, CLASS_MAGIC};
+use rw_utils::error::{ErrorKind, ResultExt};
+use rw_utils::from_read::DefaultFromRead;
+use rw_utils::leb128_read::Leb128Read;
+use rw_utils::num_write::NumWrite;
+use rw_utils::string_read::StringRead;
+use rw_utils::string_write::StringWrite;
+use rw_utils::Error;
+
+#[test]
+fn test_kinds() {
+    let err = Cursor::new(vec![0xFFu8, 0xFF, 0xFF, 0xFF]).read_leb128_u16().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(Error::from(err).kind(), ErrorKind::Overflow);
+
+    let err = Cursor::new(vec![0xC3u8, 0x28]).read_string_utf8(2).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "invalid utf-8 data");
+    assert_eq!(Error::from(err).kind(), ErrorKind::InvalidEncoding);
+
+    let err = Cursor::new(b"abcd\0".to_vec()).read_string_zero_terminated_utf8_max(2).unwrap_err();
+    assert_eq!(Error::from(err).kind(), ErrorKind::LimitExceeded);
+
+    let err = Vec::new().write_string_u16_be_len_utf8("a".repeat(65536).as_str()).unwrap_err();
+    let err = Error::from(err);
+    assert_eq!(err.kind(), ErrorKind::Overflow);
+    assert_eq!(err.io_kind(), io::ErrorKind::InvalidInput);
+
+    let err = Cursor::new(vec![0x80u8]).read_leb128_u16().unwrap_err();
+    assert_eq!(Error::from(err).kind(), ErrorKind::UnexpectedEof);
+
+    let err = Error::from(io::Error::new(io::ErrorKind::BrokenPipe, "broken"));
+    assert_eq!(err.kind(), ErrorKind::Other);
+    assert_eq!(err.io_kind(), io::ErrorKind::BrokenPipe);
+    assert_eq!(err.message(), "broken");
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn test_context() {
+    let result: io::Result<()> = Err(io::Error::new(io::ErrorKind::UnexpectedEof, "failed to fill whole buffer"));
+    let err = result.field("name").at_offset(12).at_offset(4).field("header").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(err.to_string(), "failed to fill whole buffer in field header.name at offset 12");
+
+    let err = Error::from(err);
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.offset(), Some(12));
+    assert_eq!(err.fields(), vec!["header", "name"]);
+    assert_eq!(err.field_path(), "header.name");
+
+    let err: io::Error = Error::new(ErrorKind::Overflow, "too large").with_io_kind(io::ErrorKind::InvalidInput).into();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(err.to_string(), "too large");
+
+    let err = Error::new(ErrorKind::Overflow, "too large").with_actual_length(70000).with_max_length(65535);
+    assert_eq!(err.actual_length(), Some(70000));
+    assert_eq!(err.max_length(), Some(65535));
+    assert_eq!(err.limit(), None);
+
+    let err = Error::from(io::Error::from(Error::new(ErrorKind::LimitExceeded, "too long").with_limit(16)));
+    assert_eq!(err.limit(), Some(16));
+    assert_eq!(err.actual_length(), None);
+}
+
+#[test]
+fn test_from_read_field_path() -> io::Result<()> {
+    let mut data: Vec<u8> = vec![];
+    data.write_u32_be(CLASS_MAGIC)?;
+    data.write_u16_be(0)?;
+    data.write_u16_be(52)?;
+    data.write_u16_be(2)?;
+    data.write_u8(1)?;
+    data.write_u16_be(10)?;
+    data.extend_from_slice(b"abc");
+
+    let err = Error::from(ClassFile::from_vec(&data).unwrap_err());
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(err.field_path(), "constant_pool");
+    return Ok(());
+}
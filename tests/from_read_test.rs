@@ -1,9 +1,10 @@
 use std::io;
-use std::io::{Cursor, Read, Write};
-use rw_utils::from_read::{FromRead};
+use std::num::NonZeroU32;
+use std::io::{Cursor, ErrorKind, Read, Write};
+use rw_utils::from_read::{DefaultFromRead, FromRead, FromReader, ReadFrom, RecordRead};
 use rw_utils::num_read::NumRead;
 use rw_utils::num_write::NumWrite;
-use rw_utils::to_write::ToWrite;
+use rw_utils::to_write::{ToVec, ToWrite, ToWriter};
 
 
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
@@ -46,4 +47,169 @@ fn simple_test() -> io::Result<()> {
     assert_eq!(def.a, 4);
 
     return Ok(());
-}
\ No newline at end of file
+}
+#[test]
+fn test_try_from_io() -> io::Result<()> {
+    let mut v : Vec<u8> = vec![];
+    Test { a: 1, b: 2, c: 3 }.copy_to_write(&mut v)?;
+    Test { a: 4, b: 5, c: 6 }.copy_to_write(&mut v)?;
+
+    let mut cursor = Cursor::new(&v);
+    let mut records = Vec::new();
+    while let Some(record) = Test::try_from_io(&mut cursor)? {
+        records.push(record);
+    }
+    assert_eq!(records, vec![Test { a: 1, b: 2, c: 3 }, Test { a: 4, b: 5, c: 6 }]);
+
+    let mut cursor = Cursor::new(&v[..16]);
+    assert_eq!(Test::try_from_io(&mut cursor)?, Some(Test { a: 1, b: 2, c: 3 }));
+    assert_eq!(Test::try_from_io(&mut cursor).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_iter_records() -> io::Result<()> {
+    let mut v : Vec<u8> = vec![];
+    Test { a: 1, b: 2, c: 3 }.copy_to_write(&mut v)?;
+    Test { a: 4, b: 5, c: 6 }.copy_to_write(&mut v)?;
+
+    let mut cursor = Cursor::new(&v);
+    let records = cursor.iter_records::<Test>().collect::<io::Result<Vec<Test>>>()?;
+    assert_eq!(records, vec![Test { a: 1, b: 2, c: 3 }, Test { a: 4, b: 5, c: 6 }]);
+
+    let mut cursor = Cursor::new(&v[..20]);
+    let mut iter = cursor.iter_records::<Test>();
+    assert_eq!(iter.next().unwrap()?, Test { a: 1, b: 2, c: 3 });
+    assert_eq!(iter.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert!(iter.next().is_none());
+    return Ok(());
+}
+
+#[test]
+fn test_from_slice_exact() -> io::Result<()> {
+    let mut v : Vec<u8> = vec![];
+    Test { a: 1, b: 2, c: 3 }.copy_to_write(&mut v)?;
+    assert_eq!(Test::from_slice_exact(&v)?, Test { a: 1, b: 2, c: 3 });
+    assert_eq!(Test::from_vec_exact(&v)?, Test { a: 1, b: 2, c: 3 });
+
+    v.extend_from_slice(&[7, 8]);
+    assert_eq!(Test::from_vec(&v)?, Test { a: 1, b: 2, c: 3 });
+    assert_eq!(rw_utils::Error::from(Test::from_vec_exact(&v).unwrap_err()).trailing_bytes(), Some(2));
+    let err = Test::from_slice_exact(v.as_slice()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "2 trailing bytes after reading 12 of 14 bytes at offset 12");
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::TrailingData);
+    assert_eq!(err.offset(), Some(12));
+    assert_eq!(err.trailing_bytes(), Some(2));
+
+    let (test, remainder) = Test::from_slice_partial(v.as_slice())?;
+    assert_eq!(test, Test { a: 1, b: 2, c: 3 });
+    assert_eq!(remainder, &[7, 8]);
+
+    assert_eq!(Test::from_slice_exact(&v[..8]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum Shape {
+    Circle(NonZeroU32),
+    Square(NonZeroU32),
+}
+
+impl ReadFrom for Shape {
+    fn read_from<R: Read + ?Sized>(mut reader: &mut R) -> io::Result<Self> {
+        let tag = reader.read_u8()?;
+        let size = NonZeroU32::new(reader.read_u32_le()?).ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "size is 0"))?;
+        return match tag {
+            0 => Ok(Shape::Circle(size)),
+            1 => Ok(Shape::Square(size)),
+            _ => Err(io::Error::new(ErrorKind::InvalidData, "invalid shape"))
+        };
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Drawing {
+    id: i32,
+    shape: Option<Shape>,
+}
+
+impl FromRead for Drawing {
+    fn copy_from_read(&mut self, mut reader: &mut dyn Read) -> io::Result<()> {
+        self.id = reader.read_i32_le()?;
+        self.shape.copy_from_read(reader)?;
+        return Ok(());
+    }
+}
+
+#[test]
+fn test_read_from() -> io::Result<()> {
+    let data = [0u8, 5, 0, 0, 0, 1, 7, 0, 0, 0];
+    let shapes = Cursor::new(&data).iter_records::<Shape>().collect::<io::Result<Vec<Shape>>>()?;
+    assert_eq!(shapes, vec![Shape::Circle(NonZeroU32::new(5).unwrap()), Shape::Square(NonZeroU32::new(7).unwrap())]);
+    assert_eq!(Shape::read_from(&mut &[0u8, 0, 0, 0, 0][..]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    //Types without Default can use the slice constructors.
+    assert_eq!(Shape::from_slice_exact(&data[..5])?, Shape::Circle(NonZeroU32::new(5).unwrap()));
+    assert_eq!(rw_utils::Error::from(Shape::from_slice_exact(&data).unwrap_err()).trailing_bytes(), Some(5));
+    let (shape, remainder) = Shape::from_slice_partial(&data)?;
+    assert_eq!(shape, Shape::Circle(NonZeroU32::new(5).unwrap()));
+    assert_eq!(Shape::from_slice(remainder)?, Shape::Square(NonZeroU32::new(7).unwrap()));
+    assert_eq!(Shape::try_from_io(&mut &[][..])?, None);
+
+    let data = [9u8, 0, 0, 0, 1, 3, 0, 0, 0];
+    let drawing = Drawing::from_slice_exact(&data)?;
+    assert_eq!(drawing, Drawing { id: 9, shape: Some(Shape::Square(NonZeroU32::new(3).unwrap())) });
+
+    //FromRead + Default types implement ReadFrom, also for unsized readers.
+    let reader: &mut dyn Read = &mut Cursor::new(&data);
+    assert_eq!(Drawing::read_from(reader)?, drawing);
+    return Ok(());
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Header {
+    magic: u32,
+    version: u16,
+    flags: u8,
+}
+
+impl FromReader for Header {
+    fn copy_from_reader<R: Read + ?Sized>(&mut self, mut reader: &mut R) -> io::Result<()> {
+        self.magic = reader.read_u32_be()?;
+        self.version = reader.read_u16_le()?;
+        self.flags = reader.read_u8()?;
+        return Ok(());
+    }
+}
+
+impl ToWriter for Header {
+    fn copy_to_writer<W: Write + ?Sized>(&self, mut writer: &mut W) -> io::Result<()> {
+        writer.write_u32_be(self.magic)?;
+        writer.write_u16_le(self.version)?;
+        writer.write_u8(self.flags)?;
+        return Ok(());
+    }
+}
+
+#[test]
+fn test_generic_reader_writer() -> io::Result<()> {
+    let header = Header { magic: 0xCAFEBABE, version: 3, flags: 1 };
+    let mut data: Vec<u8> = Vec::new();
+    header.copy_to_writer(&mut data)?;
+    assert_eq!(data, vec![0xCA, 0xFE, 0xBA, 0xBE, 3, 0, 1]);
+
+    let mut read = Header::default();
+    read.copy_from_reader(&mut Cursor::new(data.as_slice()))?;
+    assert_eq!(read, header);
+
+    //The dyn traits and everything built on them are available through the blanket impls.
+    assert_eq!(header.copy_to_vec()?, data);
+    let writer: &mut dyn Write = &mut Vec::new();
+    header.copy_to_write(writer)?;
+    assert_eq!(Header::from_slice_exact(data.as_slice())?, header);
+    assert_eq!(Header::read_from(&mut &data[..])?, header);
+    assert_eq!(Header::from_slice(&data[..6]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
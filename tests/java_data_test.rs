@@ -0,0 +1,97 @@
+use std::io;
+use std::io::{BufReader, Cursor};
+use rw_utils::java_data_input::JavaDataInput;
+use rw_utils::java_data_output::{double_to_long_bits, float_to_int_bits, JavaDataOutput};
+
+//Taken from a java program using java.io.DataOutputStream:
+//writeBoolean(true); writeBoolean(false); writeByte(-2); writeShort(-12345); writeChar(0xD83D);
+//writeInt(0x12345678); writeLong(-2L); writeFloat(1.5f); writeFloat(Float.intBitsToFloat(0x7f800001));
+//writeDouble(-0.25); writeDouble(Double.longBitsToDouble(0xfff0000000000001L));
+//writeBytes("aä€"); writeChars("a€💩"); writeUTF("ab\u0000💩");
+const PRIMITIVES: [u8; 66] = [1, 0, 254, 207, 199, 216, 61, 18, 52, 86, 120, 255, 255, 255, 255, 255, 255, 255, 254,
+    63, 192, 0, 0, 127, 192, 0, 0, 191, 208, 0, 0, 0, 0, 0, 0, 127, 248, 0, 0, 0, 0, 0, 0, 97, 228, 172, 0, 97, 32, 172,
+    216, 61, 220, 169, 0, 10, 97, 98, 192, 128, 237, 160, 189, 237, 178, 169];
+
+#[test]
+fn test_write_primitives() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_java_boolean(true)?;
+    vec.write_java_boolean(false)?;
+    vec.write_java_byte(-2)?;
+    vec.write_java_short(-12345)?;
+    vec.write_java_char(0xD83D)?;
+    vec.write_java_int(0x12345678)?;
+    vec.write_java_long(-2)?;
+    vec.write_java_float(1.5)?;
+    vec.write_java_float(f32::from_bits(0x7f800001))?;
+    vec.write_java_double(-0.25)?;
+    vec.write_java_double(f64::from_bits(0xfff0000000000001))?;
+    assert_eq!(vec.write_java_bytes("aä€")?, 3);
+    assert_eq!(vec.write_java_chars("a€\u{1F4A9}")?, 8);
+    assert_eq!(vec.write_java_utf("ab\0\u{1F4A9}")?, 12);
+    assert_eq!(vec.as_slice(), &PRIMITIVES[..]);
+
+    return Ok(());
+}
+
+#[test]
+fn test_read_primitives() -> io::Result<()> {
+    let mut cursor = Cursor::new(&PRIMITIVES[..]);
+    assert!(cursor.read_java_boolean()?);
+    assert!(!cursor.read_java_boolean()?);
+    assert_eq!(cursor.read_java_byte()?, -2);
+    assert_eq!(cursor.read_java_short()?, -12345);
+    assert_eq!(cursor.read_java_char()?, 0xD83D);
+    assert_eq!(cursor.read_java_int()?, 0x12345678);
+    assert_eq!(cursor.read_java_long()?, -2);
+    assert_eq!(cursor.read_java_float()?, 1.5);
+    assert_eq!(cursor.read_java_float()?.to_bits(), 0x7fc00000);
+    assert_eq!(cursor.read_java_double()?, -0.25);
+    assert_eq!(cursor.read_java_double()?.to_bits(), 0x7ff8000000000000);
+    assert_eq!(cursor.read_java_unsigned_byte()?, 97);
+    assert_eq!(cursor.read_java_unsigned_byte()?, 228);
+    assert_eq!(cursor.read_java_unsigned_byte()?, 172);
+    assert_eq!(cursor.skip_java_bytes(8)?, 8);
+    assert_eq!(cursor.read_java_unsigned_short()?, 10);
+    cursor.set_position(cursor.position() - 2);
+    assert_eq!(cursor.read_java_utf()?, "ab\0\u{1F4A9}");
+    assert_eq!(cursor.skip_java_bytes(8)?, 0);
+
+    return Ok(());
+}
+
+#[test]
+fn test_canonical_nan() {
+    assert_eq!(float_to_int_bits(f32::from_bits(0xffc00001)), 0x7fc00000);
+    assert_eq!(float_to_int_bits(-1.0), 0xbf800000u32 as i32);
+    assert_eq!(double_to_long_bits(f64::from_bits(0x7ff0000000000002)), 0x7ff8000000000000);
+    assert_eq!(double_to_long_bits(f64::INFINITY), 0x7ff0000000000000);
+}
+
+#[test]
+fn test_long_utf() -> io::Result<()> {
+    let string: String = (0..300).map(|i| (b'a' + (i % 26) as u8) as char).collect();
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_java_utf(string.as_str())?, 302);
+    //Taken from a java program
+    assert_eq!(&vec[..2], &[1, 44]);
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_java_utf()?, string);
+
+    return Ok(());
+}
+
+#[test]
+fn test_read_line() -> io::Result<()> {
+    //Same output as java.io.DataInputStream#readLine
+    let mut reader = BufReader::with_capacity(1, Cursor::new(b"one\rtwo\r\nthree\n\nfour\xE4".to_vec()));
+    assert_eq!(reader.read_java_line()?, Some("one".to_string()));
+    assert_eq!(reader.read_java_line()?, Some("two".to_string()));
+    assert_eq!(reader.read_java_line()?, Some("three".to_string()));
+    assert_eq!(reader.read_java_line()?, Some("".to_string()));
+    assert_eq!(reader.read_java_line()?, Some("fourä".to_string()));
+    assert_eq!(reader.read_java_line()?, None);
+
+    return Ok(());
+}
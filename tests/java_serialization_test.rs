@@ -0,0 +1,218 @@
+use std::io;
+use std::io::ErrorKind;
+use rw_utils::from_read::DefaultFromRead;
+use rw_utils::java_serialization::*;
+use rw_utils::to_write::ToVec;
+
+//Taken from a java program using java.io.ObjectOutputStream:
+//class Point implements Serializable { int x; double y; boolean z; String name; Point next; int[] data; Color color; }
+//class Custom implements Serializable { transient int secret = 5; String label = "c";
+//  writeObject: defaultWriteObject(); writeInt(secret); writeObject("extra"); }
+//p = {x=3, y=-1.5, z=true, name="p1", data={1, -1}, color=GREEN}; q = {x=4, name="p1", next=p, color=GREEN}
+//writeObject(q); writeObject(new Custom()); writeInt(0x01020304); writeObject(p);
+const STREAM: [u8; 338] = [172, 237, 0, 5, 115, 114, 0, 9, 83, 101, 114, 36, 80, 111, 105, 110, 116, 0, 0, 0,
+    0, 0, 0, 0, 42, 2, 0, 7, 73, 0, 1, 120, 68, 0, 1, 121, 90, 0, 1, 122,
+    76, 0, 5, 99, 111, 108, 111, 114, 116, 0, 11, 76, 83, 101, 114, 36, 67, 111, 108, 111,
+    114, 59, 91, 0, 4, 100, 97, 116, 97, 116, 0, 2, 91, 73, 76, 0, 4, 110, 97, 109,
+    101, 116, 0, 18, 76, 106, 97, 118, 97, 47, 108, 97, 110, 103, 47, 83, 116, 114, 105, 110,
+    103, 59, 76, 0, 4, 110, 101, 120, 116, 116, 0, 11, 76, 83, 101, 114, 36, 80, 111, 105,
+    110, 116, 59, 120, 112, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 114,
+    0, 9, 83, 101, 114, 36, 67, 111, 108, 111, 114, 0, 0, 0, 0, 0, 0, 0, 0, 18,
+    0, 0, 120, 114, 0, 14, 106, 97, 118, 97, 46, 108, 97, 110, 103, 46, 69, 110, 117, 109,
+    0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 120, 112, 116, 0, 5, 71, 82, 69, 69,
+    78, 112, 116, 0, 2, 112, 49, 115, 113, 0, 126, 0, 0, 0, 0, 0, 3, 191, 248, 0,
+    0, 0, 0, 0, 0, 1, 113, 0, 126, 0, 8, 117, 114, 0, 2, 91, 73, 77, 186, 96,
+    38, 118, 234, 178, 165, 2, 0, 0, 120, 112, 0, 0, 0, 2, 0, 0, 0, 1, 255, 255,
+    255, 255, 113, 0, 126, 0, 10, 112, 115, 114, 0, 10, 83, 101, 114, 36, 67, 117, 115, 116,
+    111, 109, 0, 0, 0, 0, 0, 0, 0, 7, 3, 0, 1, 76, 0, 5, 108, 97, 98, 101,
+    108, 113, 0, 126, 0, 3, 120, 112, 116, 0, 1, 99, 119, 4, 0, 0, 0, 5, 116, 0,
+    5, 101, 120, 116, 114, 97, 120, 119, 4, 1, 2, 3, 4, 113, 0, 126, 0, 11];
+
+fn object_data(object: &JavaObject) -> &ObjectData {
+    match object {
+        JavaObject::Object(data) => return data,
+        _ => panic!("not an object {:?}", object)
+    }
+}
+
+#[test]
+fn test_read_stream() -> io::Result<()> {
+    let stream = JavaSerializationStream::from_slice(&STREAM)?;
+    assert_eq!(stream.contents.len(), 4);
+
+    let q = match &stream.contents[0] {
+        Content::Object(q) => object_data(q),
+        _ => panic!()
+    };
+
+    match &q.class_desc {
+        JavaObject::ClassDesc(desc) => {
+            assert_eq!(desc.name, "Ser$Point");
+            assert_eq!(desc.serial_version_uid, 42);
+            assert_eq!(desc.flags, SC_SERIALIZABLE);
+            let names: Vec<&str> = desc.fields.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, vec!["x", "y", "z", "color", "data", "name", "next"]);
+            assert_eq!(desc.fields[3].class_name, Some(JavaObject::String("LSer$Color;".to_string())));
+            assert_eq!(desc.super_class, JavaObject::Null);
+        }
+        _ => panic!()
+    }
+
+    assert_eq!(q.class_data.len(), 1);
+    let values = &q.class_data[0].values;
+    assert_eq!(values[0], Value::Int(4));
+    assert_eq!(values[1], Value::Double(0.0));
+    assert_eq!(values[2], Value::Boolean(false));
+    assert_eq!(values[4], Value::Object(JavaObject::Null));
+    assert_eq!(values[5], Value::Object(JavaObject::String("p1".to_string())));
+    assert!(q.class_data[0].annotations.is_none());
+
+    let green = match &values[3] {
+        Value::Object(JavaObject::Enum(green)) => green,
+        _ => panic!()
+    };
+    assert_eq!(green.constant_name, JavaObject::String("GREEN".to_string()));
+
+    let p = match &values[6] {
+        Value::Object(p) => object_data(p),
+        _ => panic!()
+    };
+    assert_eq!(p.class_desc, JavaObject::Reference(BASE_WIRE_HANDLE));
+    let values = &p.class_data[0].values;
+    assert_eq!(values[0], Value::Int(3));
+    assert_eq!(values[1], Value::Double(-1.5));
+    assert_eq!(values[2], Value::Boolean(true));
+    assert_eq!(values[3], Value::Object(JavaObject::Reference(BASE_WIRE_HANDLE + 8)));
+    assert_eq!(values[5], Value::Object(JavaObject::Reference(BASE_WIRE_HANDLE + 10)));
+    match &values[4] {
+        Value::Object(JavaObject::Array(array)) => assert_eq!(array.values, ArrayValues::Int(vec![1, -1])),
+        _ => panic!()
+    }
+
+    let custom = match &stream.contents[1] {
+        Content::Object(custom) => object_data(custom),
+        _ => panic!()
+    };
+    assert_eq!(custom.class_data[0].values, vec![Value::Object(JavaObject::String("c".to_string()))]);
+    assert_eq!(custom.class_data[0].annotations, Some(vec![
+        Content::BlockData(vec![0, 0, 0, 5]),
+        Content::Object(JavaObject::String("extra".to_string())),
+    ]));
+
+    assert_eq!(stream.contents[2], Content::BlockData(vec![1, 2, 3, 4]));
+    assert_eq!(stream.contents[3], Content::Object(JavaObject::Reference(BASE_WIRE_HANDLE + 11)));
+    assert_eq!(stream.resolve(BASE_WIRE_HANDLE + 10), Some(&JavaObject::String("p1".to_string())));
+    assert_eq!(stream.resolve(BASE_WIRE_HANDLE + 11), Some(&values_owner(&stream)));
+    assert_eq!(stream.resolve(BASE_WIRE_HANDLE - 1), None);
+    return Ok(());
+}
+
+fn values_owner(stream: &JavaSerializationStream) -> JavaObject {
+    match &stream.contents[0] {
+        Content::Object(q) => match &object_data(q).class_data[0].values[6] {
+            Value::Object(p) => return p.clone(),
+            _ => panic!()
+        },
+        _ => panic!()
+    }
+}
+
+#[test]
+fn test_rewrite_stream() -> io::Result<()> {
+    let stream = JavaSerializationStream::from_slice(&STREAM)?;
+    assert_eq!(stream.copy_to_vec()?, STREAM.to_vec());
+    return Ok(());
+}
+
+#[test]
+fn test_long_values() -> io::Result<()> {
+    let long_string = "ä".repeat(40000);
+    let stream = JavaSerializationStream {
+        contents: vec![
+            Content::Object(JavaObject::String(long_string.clone())),
+            Content::BlockData(vec![7; 300]),
+            Content::Reset,
+            Content::Object(JavaObject::String("a".to_string())),
+        ]
+    };
+
+    let data = stream.copy_to_vec()?;
+    assert_eq!(data[4], TC_LONGSTRING);
+    assert_eq!(&data[5..13], &[0, 0, 0, 0, 0, 1, 56, 128]);
+    assert_eq!(data[80013], TC_BLOCKDATALONG);
+    assert_eq!(JavaSerializationStream::from_vec(&data)?, stream);
+    assert_eq!(stream.resolve(BASE_WIRE_HANDLE), Some(&JavaObject::String("a".to_string())));
+    return Ok(());
+}
+
+#[test]
+fn test_invalid_streams() {
+    assert_eq!(JavaSerializationStream::from_slice(&[0xAC, 0xEE, 0, 5]).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(JavaSerializationStream::from_slice(&[0xAC, 0xED, 0, 4]).unwrap_err().kind(), ErrorKind::Unsupported);
+    assert_eq!(JavaSerializationStream::from_slice(&[0xAC, 0xED, 0, 5, 0x71, 0, 0x7E, 0, 0]).unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(JavaSerializationStream::from_slice(&STREAM[..300]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    //Class descriptor with a field count of -1
+    let err = JavaSerializationStream::from_slice(&[0xAC, 0xED, 0, 5, 0x72, 0, 1, b'A', 0, 0, 0, 0, 0, 0, 0, 1, 2, 0xFF, 0xFF, 0x78, 0x70]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "negative field count in field contents at offset 19");
+    assert_eq!(JavaSerializationStream::from_slice(&[0xAC, 0xED, 0, 5]).unwrap().contents, vec![]);
+}
+
+///
+/// Creates a stream of Object[] arrays that each contain the next array as their only element.
+///
+fn nested_arrays(depth: usize) -> Vec<u8> {
+    let mut data = vec![0xAC, 0xED, 0, 5];
+    data.extend_from_slice(&[TC_ARRAY, TC_CLASSDESC, 0, 19]);
+    data.extend_from_slice(b"[Ljava.lang.Object;");
+    data.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1, SC_SERIALIZABLE, 0, 0, TC_ENDBLOCKDATA, TC_NULL, 0, 0, 0, 1]);
+    for _ in 1 .. depth {
+        data.extend_from_slice(&[TC_ARRAY, TC_REFERENCE, 0, 0x7E, 0, 0, 0, 0, 0, 1]);
+    }
+
+    data.push(TC_NULL);
+    return data;
+}
+
+#[test]
+fn test_max_depth() -> io::Result<()> {
+    //The null element of the innermost array is one level deeper than the array.
+    let stream = JavaSerializationStream::from_slice(nested_arrays(MAX_DEPTH - 1).as_slice())?;
+    assert_eq!(stream.contents.len(), 1);
+
+    for depth in [MAX_DEPTH, 100_000] {
+        let err = JavaSerializationStream::from_slice(nested_arrays(depth).as_slice()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        let err = rw_utils::Error::from(err);
+        assert_eq!(err.kind(), rw_utils::error::ErrorKind::LimitExceeded);
+        assert_eq!(err.limit(), Some(MAX_DEPTH as u64));
+    }
+
+    return Ok(());
+}
+
+#[test]
+fn test_error_fields() {
+    let err = rw_utils::Error::from(JavaSerializationStream::from_slice(&STREAM[..300]).unwrap_err());
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::UnexpectedEof);
+    assert_eq!(err.field_path(), "contents.class_desc.fields");
+}
+
+#[test]
+fn test_error_offset() {
+    let err = rw_utils::Error::from(JavaSerializationStream::from_slice(&[0xAC, 0xEE, 0, 5]).unwrap_err());
+    assert_eq!(err.offset(), Some(2));
+
+    //Reference to an unknown handle directly after the header
+    let err = rw_utils::Error::from(JavaSerializationStream::from_slice(&[0xAC, 0xED, 0, 5, 0x71, 0, 0x7E, 0, 0]).unwrap_err());
+    assert_eq!(err.offset(), Some(9));
+    assert_eq!(err.field_path(), "contents");
+
+    let err = rw_utils::Error::from(JavaSerializationStream::from_slice(&STREAM[..300]).unwrap_err());
+    assert_eq!(err.offset(), Some(300));
+
+    //The class descriptor reference of the innermost array exceeds the depth, its handle, size and null element are not read.
+    let data = nested_arrays(MAX_DEPTH);
+    let err = rw_utils::Error::from(JavaSerializationStream::from_slice(data.as_slice()).unwrap_err());
+    assert_eq!(err.offset(), Some(data.len() as u64 - 9));
+}
@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{Cursor};
+use std::io::{Cursor, ErrorKind};
 use rw_utils::leb128_read::Leb128Read;
 use rw_utils::leb128_write::Leb128Write;
 use rw_utils::num_read::NumRead;
@@ -292,3 +292,48 @@ fn test_leb_i32() -> io::Result<()>{
 
     return Ok(());
 }
+
+#[test]
+fn test_leb128p1() -> io::Result<()> {
+    let mut buf: Vec<u8> = vec![];
+    buf.write_leb128p1_u32(u32::MAX)?;
+    buf.write_leb128p1_u32(0)?;
+    buf.write_leb128p1_u32(127)?;
+    buf.write_leb128p1_u32(u32::MAX - 1)?;
+    assert_eq!(buf, vec![0, 1, 0x80, 1, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+
+    let mut cursor = Cursor::new(&buf);
+    assert_eq!(cursor.read_leb128p1_u32()?, u32::MAX);
+    assert_eq!(cursor.read_leb128p1_u32()?, 0);
+    assert_eq!(cursor.read_leb128p1_u32()?, 127);
+    assert_eq!(cursor.read_leb128p1_u32()?, u32::MAX - 1);
+    return Ok(());
+}
+
+#[test]
+fn test_try_read_leb() -> io::Result<()> {
+    let mut cursor = Cursor::new(vec![0xE5u8, 0x8E, 0x26, 0x00, 0x7F, 0x80]);
+    assert_eq!(cursor.try_read_leb128_u32()?, Some(624485));
+    assert_eq!(cursor.try_read_leb128p1_u32()?, Some(u32::MAX));
+    assert_eq!(cursor.try_read_leb128_i64()?, Some(-1));
+    assert_eq!(cursor.try_read_leb128_u16().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(cursor.try_read_leb128_u16()?, None);
+    return Ok(());
+}
+
+#[test]
+fn test_iter_leb() -> io::Result<()> {
+    let values = [0u64, 127, 128, 624485, u64::MAX];
+    let mut buffer: Vec<u8> = vec![];
+    buffer.write_all_leb128_u64(values.iter())?;
+    buffer.write_all_leb128_i32([-1, 63, -64].iter())?;
+
+    let mut cursor = Cursor::new(&buffer);
+    let read: Vec<u64> = cursor.iter_leb128_u64().take(5).collect::<io::Result<_>>()?;
+    assert_eq!(read, values.to_vec());
+    assert_eq!(cursor.iter_leb128_i32().collect::<io::Result<Vec<i32>>>()?, vec![-1, 63, -64]);
+
+    let mut cursor = Cursor::new(vec![0x80u8]);
+    assert_eq!(cursor.iter_leb128_u32().next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
@@ -0,0 +1,62 @@
+use std::io;
+use std::io::{Cursor, ErrorKind, Write};
+use rw_utils::length_prefix::{LengthPrefixWrite, PrefixKind};
+use rw_utils::leb128_read::Leb128Read;
+use rw_utils::num_read::NumRead;
+use rw_utils::num_write::NumWrite;
+
+fn write_nested(mut w: &mut dyn Write) -> io::Result<()> {
+    w.write_u8(0xAA)?;
+    w.with_length_prefix(PrefixKind::Leb128, |inner| {
+        inner.write_all(&[0x55u8; 300])?;
+        return Ok(());
+    })?;
+    w.write_u16_be(0x1234)?;
+    return Ok(());
+}
+
+#[test]
+fn test_buffered() -> io::Result<()> {
+    let mut data: Vec<u8> = vec![];
+    assert_eq!(data.with_length_prefix(PrefixKind::U32LE, write_nested)?, 305);
+    assert_eq!(data.len(), 309);
+
+    let mut cursor = Cursor::new(&data);
+    assert_eq!(cursor.read_u32_le()?, 305);
+    assert_eq!(cursor.read_u8()?, 0xAA);
+    assert_eq!(cursor.read_leb128_u64()?, 300);
+    cursor.set_position(cursor.position() + 300);
+    assert_eq!(cursor.read_u16_be()?, 0x1234);
+    return Ok(());
+}
+
+#[test]
+fn test_seek() -> io::Result<()> {
+    let mut cursor = Cursor::new(Vec::new());
+    cursor.write_u8(1)?;
+    assert_eq!(cursor.with_length_prefix_seek(PrefixKind::U16BE, write_nested)?, 305);
+    cursor.write_u8(2)?;
+    assert_eq!(cursor.with_length_prefix_seek(PrefixKind::Leb128, |mut w| w.write_u8(3))?, 1);
+
+    let mut expected: Vec<u8> = vec![1];
+    expected.with_length_prefix(PrefixKind::U16BE, write_nested)?;
+    expected.extend_from_slice(&[2, 1, 3]);
+    assert_eq!(cursor.into_inner(), expected);
+    return Ok(());
+}
+
+#[test]
+fn test_overflow() -> io::Result<()> {
+    let mut data: Vec<u8> = vec![];
+    let err = data.with_length_prefix(PrefixKind::U8, write_nested).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    assert_eq!(rw_utils::Error::from(err).kind(), rw_utils::error::ErrorKind::Overflow);
+    assert!(data.is_empty());
+
+    let mut cursor = Cursor::new(Vec::new());
+    assert_eq!(cursor.with_length_prefix_seek(PrefixKind::U8, write_nested).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(cursor.position(), 0);
+    cursor.write_u8(9)?;
+    assert_eq!(cursor.into_inner()[0], 9);
+    return Ok(());
+}
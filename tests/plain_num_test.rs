@@ -1,5 +1,5 @@
 use std::io;
-use std::io::Cursor;
+use std::io::{Cursor, ErrorKind};
 use rw_utils::num_read::NumRead;
 use rw_utils::num_write::NumWrite;
 
@@ -242,3 +242,44 @@ fn test_i128() -> io::Result<()> {
 }
 
 
+
+#[test]
+fn test_try_read() -> io::Result<()> {
+    let mut buffer: Vec<u8> = vec![];
+    buffer.write_u32_le(0x12345678)?;
+    buffer.write_f64_be(1.5)?;
+    buffer.write_u8(7)?;
+    let mut cursor = Cursor::new(&buffer);
+    assert_eq!(cursor.try_read_u32_le()?, Some(0x12345678));
+    assert_eq!(cursor.try_read_f64_be()?, Some(1.5));
+    assert_eq!(cursor.try_read_bool()?, Some(true));
+    assert_eq!(cursor.try_read_u8()?, None);
+    assert_eq!(cursor.try_read_i128_ne()?, None);
+
+    let mut cursor = Cursor::new(&buffer[..3]);
+    assert_eq!(cursor.try_read_u32_le().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_iter() -> io::Result<()> {
+    let values = [1u32, 0x12345678, u32::MAX];
+    let mut buffer: Vec<u8> = vec![];
+    buffer.write_all_u32_le(values.iter())?;
+    buffer.write_all_f32_be(vec![1.5f32, -2.0])?;
+    assert_eq!(buffer.len(), 20);
+
+    let mut cursor = Cursor::new(&buffer[..12]);
+    assert_eq!(cursor.iter_u32_le().collect::<io::Result<Vec<u32>>>()?, values.to_vec());
+
+    let mut cursor = Cursor::new(&buffer[12..]);
+    assert_eq!(cursor.iter_f32_be().collect::<io::Result<Vec<f32>>>()?, vec![1.5, -2.0]);
+
+    let mut cursor = Cursor::new(&buffer[..10]);
+    let mut iter = cursor.iter_u32_le();
+    assert_eq!(iter.next().unwrap()?, 1);
+    assert_eq!(iter.next().unwrap()?, 0x12345678);
+    assert_eq!(iter.next().unwrap().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert!(iter.next().is_none());
+    return Ok(());
+}
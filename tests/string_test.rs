@@ -1,8 +1,9 @@
 use std::{io, panic};
-use std::io::{Cursor, ErrorKind};
+use std::io::{BufReader, Cursor, ErrorKind};
 use static_assertions::const_assert;
-use rw_utils::string_read::StringRead;
+use rw_utils::string_read::{BufStringRead, StringRead};
 use rw_utils::string_write::StringWrite;
+use rw_utils::string_types::{Padding, TextEncoding};
 
 const_assert!(std::mem::size_of::<char>() == std::mem::size_of::<u32>());
 #[test]
@@ -143,4 +144,391 @@ fn test_jstring() -> io::Result<()> {
     }
 
     return Ok(());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_zero_terminated_utf16() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_zero_terminated_utf16_le("Ab\u{1F4A9}")?, 10);
+    assert_eq!(vec, vec![b'A', 0, b'b', 0, 0x3D, 0xD8, 0xA9, 0xDC, 0, 0]);
+    assert_eq!(vec.write_string_zero_terminated_utf16_be("Ab\0")?, 6);
+    assert_eq!(&vec[10..], &[0, b'A', 0, b'b', 0, 0]);
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf16_le()?, "Ab\u{1F4A9}");
+    assert_eq!(cursor.read_string_zero_terminated_utf16_be()?, "Ab");
+
+    let mut cursor = Cursor::new(vec![b'A', 0, b'b', 0]);
+    assert_eq!(cursor.read_string_zero_terminated_utf16_le().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    let mut cursor = Cursor::new(vec![0x00, 0xDC, 0, 0]);
+    assert_eq!(cursor.read_string_zero_terminated_utf16_le().unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let err = vec.write_string_zero_terminated_utf16_le("A\0b").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_zero_terminated_utf16_le("")?, 2);
+    assert_eq!(vec.write_string_zero_terminated_utf16_be("")?, 2);
+    assert_eq!(vec, vec![0, 0, 0, 0]);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf16_le()?, "");
+    assert_eq!(cursor.read_string_zero_terminated_utf16_be()?, "");
+
+    return Ok(());
+}
+
+#[test]
+fn test_zero_terminated_utf32() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_zero_terminated_utf32_le("A\u{1F4A9}")?, 12);
+    assert_eq!(vec, vec![b'A', 0, 0, 0, 0xA9, 0xF4, 0x01, 0, 0, 0, 0, 0]);
+    assert_eq!(vec.write_string_zero_terminated_utf32_be("A")?, 8);
+    assert_eq!(&vec[12..], &[0, 0, 0, b'A', 0, 0, 0, 0]);
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf32_le()?, "A\u{1F4A9}");
+    assert_eq!(cursor.read_string_zero_terminated_utf32_be()?, "A");
+
+    let mut cursor = Cursor::new(vec![0, 0xD8, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(cursor.read_string_zero_terminated_utf32_le().unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let err = vec.write_string_zero_terminated_utf32_be("\0A").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_zero_terminated_utf32_le("")?, 4);
+    assert_eq!(vec.write_string_zero_terminated_utf32_be("")?, 4);
+    assert_eq!(vec, vec![0; 8]);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf32_le()?, "");
+    assert_eq!(cursor.read_string_zero_terminated_utf32_be()?, "");
+
+    return Ok(());
+}
+
+#[test]
+fn test_fixed() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_fixed("abc", 6, TextEncoding::Utf8, Padding::Zero)?, 6);
+    assert_eq!(vec.write_string_fixed("ab", 4, TextEncoding::Utf8, Padding::Space)?, 4);
+    assert_eq!(vec.write_string_fixed("ä", 8, TextEncoding::Utf16BE, Padding::Space)?, 8);
+    assert_eq!(vec.write_string_fixed("x", 8, TextEncoding::Utf32LE, Padding::Zero)?, 8);
+    assert_eq!(vec.write_string_fixed("full", 4, TextEncoding::Utf8, Padding::Zero)?, 4);
+    assert_eq!(vec, vec![
+        b'a', b'b', b'c', 0, 0, 0,
+        b'a', b'b', b' ', b' ',
+        0, 0xE4, 0, b' ', 0, b' ', 0, b' ',
+        b'x', 0, 0, 0, 0, 0, 0, 0,
+        b'f', b'u', b'l', b'l']);
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_fixed(6, TextEncoding::Utf8, Padding::Zero)?, "abc");
+    assert_eq!(cursor.read_string_fixed(4, TextEncoding::Utf8, Padding::Space)?, "ab");
+    assert_eq!(cursor.read_string_fixed(8, TextEncoding::Utf16BE, Padding::Space)?, "ä");
+    assert_eq!(cursor.read_string_fixed(8, TextEncoding::Utf32LE, Padding::Zero)?, "x");
+    assert_eq!(cursor.read_string_fixed(4, TextEncoding::Utf8, Padding::Zero)?, "full");
+
+    //garbage after the terminator of a C char array is ignored
+    let mut cursor = Cursor::new(vec![b'a', 0, 0xFF, 0xFE]);
+    assert_eq!(cursor.read_string_fixed(4, TextEncoding::Utf8, Padding::Zero)?, "a");
+
+    assert_eq!(vec.write_string_fixed("too long", 4, TextEncoding::Utf8, Padding::Zero).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(vec.write_string_fixed("a\0b", 4, TextEncoding::Utf8, Padding::Zero).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(vec.write_string_fixed("a", 3, TextEncoding::Utf16LE, Padding::Zero).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    return Ok(());
+}
+
+fn assert_max_length_exceeded(err: io::Error, max_len: usize) {
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::LimitExceeded);
+    assert_eq!(err.limit(), Some(max_len as u64));
+}
+
+#[test]
+fn test_zero_terminated_utf8_empty() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_zero_terminated_utf8("")?, 1);
+    assert_eq!(vec.write_string_zero_terminated_utf8("a")?, 2);
+    assert_eq!(vec, vec![0, b'a', 0]);
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf8()?, "");
+    assert_eq!(cursor.read_string_zero_terminated_utf8()?, "a");
+    return Ok(());
+}
+
+#[test]
+fn test_zero_terminated_max() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_zero_terminated_utf8("abcd")?;
+    vec.write_string_zero_terminated_utf16_le("abcd")?;
+    vec.write_string_zero_terminated_utf32_be("abcd")?;
+
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_zero_terminated_utf8_max(4)?, "abcd");
+    assert_eq!(cursor.read_string_zero_terminated_utf16_le_max(4)?, "abcd");
+    assert_eq!(cursor.read_string_zero_terminated_utf32_be_max(4)?, "abcd");
+
+    let mut cursor = Cursor::new(&vec);
+    assert_max_length_exceeded(cursor.read_string_zero_terminated_utf8_max(3).unwrap_err(), 3);
+
+    let mut cursor = Cursor::new(&vec[5..]);
+    assert_max_length_exceeded(cursor.read_string_zero_terminated_utf16_le_max(2).unwrap_err(), 2);
+
+    let mut cursor = Cursor::new(vec![b'a'; 1024]);
+    assert_max_length_exceeded(cursor.read_string_zero_terminated_utf8_max(16).unwrap_err(), 16);
+    assert_eq!(cursor.position(), 17);
+
+    return Ok(());
+}
+
+#[test]
+fn test_zero_terminated_max_buf() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_zero_terminated_utf8("abcd")?;
+    vec.write_string_zero_terminated_utf16_be("a\u{1F4A9}")?;
+    vec.write_string_zero_terminated_utf32_le("xyz")?;
+    vec.write_string_zero_terminated_utf16_le("abc")?;
+
+    //A tiny buffer forces code units to be split across multiple fill_buf calls.
+    let mut reader = BufReader::with_capacity(3, Cursor::new(&vec));
+    assert_eq!(reader.read_string_zero_terminated_utf8_max_buf(4)?, "abcd");
+    assert_eq!(reader.read_string_zero_terminated_utf16_be_max_buf(3)?, "a\u{1F4A9}");
+    assert_eq!(reader.read_string_zero_terminated_utf32_le_max_buf(usize::MAX)?, "xyz");
+    assert_max_length_exceeded(reader.read_string_zero_terminated_utf16_le_max_buf(2).unwrap_err(), 2);
+
+    let mut reader = Cursor::new(vec![b'a', b'b']);
+    assert_eq!(reader.read_string_zero_terminated_utf8_max_buf(16).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    //The whole data is searched at once, zero bytes spanning two code units are no terminator.
+    let mut reader = Cursor::new(vec![0, b'a', b'a', 0, 0, 0, b'x']);
+    assert_eq!(reader.read_string_zero_terminated_utf16_be_max_buf(8)?, "a\u{6100}");
+    assert_eq!(reader.position(), 6);
+
+    let mut reader = Cursor::new(vec![b'a'; 1024]);
+    assert_max_length_exceeded(reader.read_string_zero_terminated_utf8_max_buf(16).unwrap_err(), 16);
+    assert_eq!(reader.position(), 17);
+
+    return Ok(());
+}
+
+#[test]
+fn test_bom_detection() -> io::Result<()> {
+    let encodings = [TextEncoding::Utf8, TextEncoding::Utf16LE, TextEncoding::Utf16BE, TextEncoding::Utf32LE, TextEncoding::Utf32BE];
+    for encoding in encodings.iter() {
+        let mut vec: Vec<u8> = vec![];
+        let len = vec.write_string_bom("Aä\u{1F4A9}", *encoding)?;
+        assert_eq!(len, vec.len());
+        let mut cursor = Cursor::new(&vec);
+        assert_eq!(cursor.read_string_unicode_auto(len)?, "Aä\u{1F4A9}", "{:?}", encoding);
+    }
+
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_bom("A", TextEncoding::Utf16LE)?;
+    assert_eq!(vec, vec![0xFF, 0xFE, b'A', 0]);
+    vec.write_string_bom("A", TextEncoding::Utf16BE)?;
+    vec.write_string_utf16_be("A")?;
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_utf16_bom(2)?, "A");
+    assert_eq!(cursor.read_string_utf16_bom(2)?, "A");
+    assert_eq!(cursor.read_string_utf16_bom(1)?, "A");
+
+    //utf-16-le bom followed by a null char looks like the utf-32-le bom
+    let mut cursor = Cursor::new(vec![0xFF, 0xFE, 0, 0, b'A', 0]);
+    assert_eq!(cursor.read_string_utf16_bom(3)?, "\0A");
+
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_bom("AB", TextEncoding::Utf32LE)?;
+    vec.write_string_utf32_be("C")?;
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_utf32_bom(3)?, "AB");
+    assert_eq!(cursor.read_string_utf32_bom(1)?, "C");
+
+    //Without bom the data is utf-8
+    let mut cursor = Cursor::new(b"abc".to_vec());
+    assert_eq!(cursor.read_string_unicode_auto(3)?, "abc");
+
+    //utf-16 bom followed by an odd amount of bytes
+    let mut cursor = Cursor::new(vec![0xFF, 0xFE, b'A']);
+    assert_eq!(cursor.read_string_unicode_auto(3).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    return Ok(());
+}
+
+#[test]
+fn test_utf16_chars() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf16_le("a\u{1F4A9}b")?;
+    vec.write_string_utf16_be("\u{1F4A9}\u{1F4A9}c")?;
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_utf16_le_chars(3)?, "a\u{1F4A9}b");
+    assert_eq!(cursor.read_string_utf16_be_chars(3)?, "\u{1F4A9}\u{1F4A9}c");
+    assert_eq!(cursor.position() as usize, vec.len());
+
+    //high surrogate followed by a normal character
+    let mut cursor = Cursor::new(vec![0x3D, 0xD8, b'a', 0]);
+    let err = cursor.read_string_utf16_le_chars(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "unpaired high surrogate in utf-16 data");
+
+    return Ok(());
+}
+
+#[test]
+fn test_utf16_split_surrogate() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf16_le("a\u{1F4A9}")?;
+
+    let mut cursor = Cursor::new(&vec);
+    let err = cursor.read_string_utf16_le(2).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "utf-16 data ends in the middle of a surrogate pair");
+
+    let mut cursor = Cursor::new(&vec[4..]);
+    let err = cursor.read_string_utf16_le(1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "utf-16 data starts in the middle of a surrogate pair");
+
+    return Ok(());
+}
+
+#[test]
+fn test_java_modified_utf8() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_java_modified_utf8("a\0ä\u{1F4A9}")?, 11);
+    assert_eq!(vec, vec![b'a', 0xC0, 0x80, 0xC3, 0xA4, 0xED, 0xA0, 0xBD, 0xED, 0xB2, 0xA9]);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_java_modified_utf8(vec.len())?, "a\0ä\u{1F4A9}");
+
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_java_modified_utf8_zero_terminated("a\0b")?, 5);
+    assert_eq!(vec.write_java_modified_utf8_zero_terminated("")?, 1);
+    assert_eq!(vec, vec![b'a', 0xC0, 0x80, b'b', 0, 0]);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_java_modified_utf8_zero_terminated()?, "a\0b");
+    assert_eq!(cursor.read_java_modified_utf8_zero_terminated()?, "");
+
+    let mut reader = BufReader::with_capacity(2, Cursor::new(&vec));
+    assert_eq!(reader.read_java_modified_utf8_zero_terminated_max_buf(4)?, "a\0b");
+    let mut cursor = Cursor::new(&vec);
+    assert_max_length_exceeded(cursor.read_java_modified_utf8_zero_terminated_max(3).unwrap_err(), 3);
+
+    return Ok(());
+}
+
+#[test]
+fn test_cesu8() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_cesu8("a\0\u{1F4A9}")?, 8);
+    assert_eq!(vec, vec![b'a', 0, 0xED, 0xA0, 0xBD, 0xED, 0xB2, 0xA9]);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_cesu8(vec.len())?, "a\0\u{1F4A9}");
+
+    //the java encoding of the null char is an overlong sequence in CESU-8
+    let mut cursor = Cursor::new(vec![0xC0, 0x80]);
+    assert_eq!(cursor.read_string_cesu8(2).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    return Ok(());
+}
+
+#[test]
+fn test_char_round_trip() -> io::Result<()> {
+    let chars = ['a', 'ä', '€', '\u{1F4A9}', '\0', '\u{10FFFF}'];
+    let mut vec: Vec<u8> = vec![];
+    for c in chars.iter() {
+        vec.write_char_utf8(*c)?;
+        vec.write_char_utf16_le(*c)?;
+        vec.write_char_utf16_be(*c)?;
+        vec.write_char_utf32_le(*c)?;
+        vec.write_char_utf32_be(*c)?;
+        vec.write_char_java_modified_utf8(*c)?;
+    }
+
+    let mut cursor = Cursor::new(&vec);
+    for c in chars.iter() {
+        assert_eq!(cursor.read_char_utf8()?, *c);
+        assert_eq!(cursor.read_char_utf16_le()?, *c);
+        assert_eq!(cursor.read_char_utf16_be()?, *c);
+        assert_eq!(cursor.read_char_utf32_le()?, *c);
+        assert_eq!(cursor.read_char_utf32_be()?, *c);
+        assert_eq!(cursor.read_char_java_modified_utf8()?, *c);
+    }
+    assert_eq!(cursor.position() as usize, vec.len());
+
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_char_utf8('\u{1F4A9}')?, 4);
+    assert_eq!(vec.write_char_utf16_be('\u{1F4A9}')?, 4);
+    assert_eq!(vec.write_char_utf16_le('€')?, 2);
+    assert_eq!(vec.write_char_utf32_be('€')?, 4);
+    assert_eq!(vec, vec![0xF0, 0x9F, 0x92, 0xA9, 0xD8, 0x3D, 0xDC, 0xA9, 0xAC, 0x20, 0, 0, 0x20, 0xAC]);
+
+    return Ok(());
+}
+
+#[test]
+fn test_invalid_char() {
+    let invalid_utf8: [&[u8]; 6] = [&[0x80], &[0xC0, 0x80], &[0xED, 0xA0, 0x80], &[0xF4, 0x90, 0x80, 0x80], &[0xE2, 0x28, 0xA1], &[0xFF]];
+    for data in invalid_utf8.iter() {
+        assert_eq!(Cursor::new(data).read_char_utf8().unwrap_err().kind(), ErrorKind::InvalidData, "{:?}", data);
+    }
+    assert_eq!(Cursor::new([0xE2, 0x82]).read_char_utf8().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+    assert_eq!(Cursor::new([0x00, 0xDC]).read_char_utf16_le().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new([0xD8, 0x3D, 0x00, 0x41]).read_char_utf16_be().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new([0xD8, 0x3D]).read_char_utf16_be().unwrap_err().kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(Cursor::new([0x00, 0xD8, 0x00, 0x00]).read_char_utf32_le().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert_eq!(Cursor::new([0x00, 0x11, 0x00, 0x00]).read_char_utf32_be().unwrap_err().kind(), ErrorKind::InvalidData);
+}
+
+fn assert_length_overflow(err: io::Error, actual: usize, max: usize) {
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = rw_utils::Error::from(err);
+    assert_eq!(err.kind(), rw_utils::error::ErrorKind::Overflow);
+    assert_eq!(err.actual_length(), Some(actual as u64));
+    assert_eq!(err.max_length(), Some(max as u64));
+}
+
+#[test]
+fn test_length_prefix_overflow() -> io::Result<()> {
+    let long = "a".repeat(65536);
+    let mut vec: Vec<u8> = vec![];
+    assert_length_overflow(vec.write_string_u16_le_len_utf8(long.as_str()).unwrap_err(), 65536, 65535);
+    assert_length_overflow(vec.write_string_u16_be_len_utf8(long.as_str()).unwrap_err(), 65536, 65535);
+    assert_length_overflow(vec.write_java_data_output_utf(long.as_str()).unwrap_err(), 65536, 65535);
+    //3 bytes each in java's modified utf-8
+    assert_length_overflow(vec.write_java_data_output_utf("\u{20AC}".repeat(21846).as_str()).unwrap_err(), 65538, 65535);
+    assert!(vec.is_empty());
+
+    assert_eq!(vec.write_string_u32_le_len_utf8(long.as_str())?, 65540);
+    assert_eq!(&vec[..4], &[0, 0, 1, 0]);
+    return Ok(());
+}
+
+#[test]
+fn test_length_prefix_truncate() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_u16_be_len_utf8_truncate("abc")?, 5);
+    assert_eq!(vec, vec![0, 3, b'a', b'b', b'c']);
+
+    //The 2 byte char at the end does not fit and is dropped entirely.
+    let long = "a".repeat(65534) + "ä";
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_string_u16_le_len_utf8_truncate(long.as_str())?, 65536);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_string_u16_le_len_utf8()?, "a".repeat(65534));
+
+    //The surrogate pair at the end needs 6 bytes but only 5 are left.
+    let long = "a".repeat(65530) + "\u{1F4A9}";
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_java_data_output_utf_truncate(long.as_str())?, 65532);
+    let mut cursor = Cursor::new(&vec);
+    assert_eq!(cursor.read_java_data_input_utf()?, "a".repeat(65530));
+
+    let mut vec: Vec<u8> = vec![];
+    assert_eq!(vec.write_java_data_output_utf_truncate("\0ä")?, 6);
+    assert_eq!(vec, vec![0, 4, 0xC0, 0x80, 0xC3, 0xA4]);
+    return Ok(());
+}
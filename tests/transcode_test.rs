@@ -0,0 +1,178 @@
+use std::io;
+use std::io::{BufRead, Cursor, ErrorKind, Read, Write};
+use rw_utils::string_write::StringWrite;
+use rw_utils::transcode::{TranscodeEncoding, TranscodeReader, TranscodeWriter};
+
+const TEXT: &str = "Hello\nWörld\n€ \u{1F4A9}\n\0end";
+
+fn encode(encoding: TranscodeEncoding, string: &str) -> io::Result<Vec<u8>> {
+    let mut writer = TranscodeWriter::new(Vec::new(), encoding);
+    writer.write_all(string.as_bytes())?;
+    return writer.finish();
+}
+
+#[test]
+fn test_read_utf16_utf32() -> io::Result<()> {
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf16_le(TEXT)?;
+    let mut string = String::new();
+    TranscodeReader::new(Cursor::new(vec), TranscodeEncoding::Utf16LE).read_to_string(&mut string)?;
+    assert_eq!(string, TEXT);
+
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf16_be(TEXT)?;
+    let lines: Vec<String> = TranscodeReader::new(Cursor::new(vec), TranscodeEncoding::Utf16BE).lines().collect::<io::Result<_>>()?;
+    assert_eq!(lines, vec!["Hello", "Wörld", "€ \u{1F4A9}", "\0end"]);
+
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf32_le(TEXT)?;
+    let mut string = String::new();
+    TranscodeReader::new(Cursor::new(vec), TranscodeEncoding::Utf32LE).read_to_string(&mut string)?;
+    assert_eq!(string, TEXT);
+
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf32_be(TEXT)?;
+    let mut string = String::new();
+    TranscodeReader::new(Cursor::new(vec), TranscodeEncoding::Utf32BE).read_to_string(&mut string)?;
+    assert_eq!(string, TEXT);
+    return Ok(());
+}
+
+#[test]
+fn test_read_latin1_and_modified_utf8() -> io::Result<()> {
+    let data = [0x48u8, 0xE4, 0x0A, 0xFF];
+    let mut string = String::new();
+    TranscodeReader::new(&data[..], TranscodeEncoding::Latin1).read_to_string(&mut string)?;
+    assert_eq!(string, "Hä\nÿ");
+
+    let data = [0x41u8, 0xC0, 0x80, 0xED, 0xA0, 0xBD, 0xED, 0xB2, 0xA9, 0xE2, 0x82, 0xAC];
+    let mut string = String::new();
+    TranscodeReader::new(&data[..], TranscodeEncoding::JavaModifiedUtf8).read_to_string(&mut string)?;
+    assert_eq!(string, "A\0\u{1F4A9}€");
+    return Ok(());
+}
+
+#[test]
+fn test_read_large() -> io::Result<()> {
+    let text = "ab€\u{1F4A9}".repeat(10000);
+    let mut vec: Vec<u8> = vec![];
+    vec.write_string_utf16_le(text.as_str())?;
+    let mut reader = TranscodeReader::new(Cursor::new(vec), TranscodeEncoding::Utf16LE);
+    let mut string = String::new();
+    reader.read_to_string(&mut string)?;
+    assert_eq!(string, text);
+    assert_eq!(reader.encoding(), TranscodeEncoding::Utf16LE);
+    assert_eq!(reader.into_inner().position() as usize, text.encode_utf16().count() * 2);
+    return Ok(());
+}
+
+#[test]
+fn test_read_invalid() -> io::Result<()> {
+    //Valid data before the unpaired surrogate is returned first.
+    let data = [0x41u8, 0x00, 0x42, 0x00, 0x00, 0xDC, 0x43, 0x00];
+    let mut reader = TranscodeReader::new(&data[..], TranscodeEncoding::Utf16LE);
+    let mut buf = [0u8; 16];
+    assert_eq!(reader.read(&mut buf)?, 2);
+    assert_eq!(&buf[..2], b"AB");
+    assert_eq!(reader.read(&mut buf).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let data = [0x41u8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00];
+    let mut string = String::new();
+    let err = TranscodeReader::new(&data[..], TranscodeEncoding::Utf32LE).read_to_string(&mut string).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+    let data = [0x41u8, 0x00, 0x42];
+    let mut string = String::new();
+    let err = TranscodeReader::new(&data[..], TranscodeEncoding::Utf16LE).read_to_string(&mut string).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    return Ok(());
+}
+
+#[test]
+fn test_write() -> io::Result<()> {
+    let mut expected: Vec<u8> = vec![];
+    expected.write_string_utf16_be(TEXT)?;
+    assert_eq!(encode(TranscodeEncoding::Utf16BE, TEXT)?, expected);
+
+    let mut expected: Vec<u8> = vec![];
+    expected.write_string_utf32_le(TEXT)?;
+    assert_eq!(encode(TranscodeEncoding::Utf32LE, TEXT)?, expected);
+
+    assert_eq!(encode(TranscodeEncoding::Latin1, "Hä\nÿ")?, vec![0x48, 0xE4, 0x0A, 0xFF]);
+    assert_eq!(encode(TranscodeEncoding::JavaModifiedUtf8, "A\0\u{1F4A9}€")?,
+               vec![0x41, 0xC0, 0x80, 0xED, 0xA0, 0xBD, 0xED, 0xB2, 0xA9, 0xE2, 0x82, 0xAC]);
+    return Ok(());
+}
+
+#[test]
+fn test_write_split_chars() -> io::Result<()> {
+    let bytes = TEXT.as_bytes();
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    for b in bytes {
+        assert_eq!(writer.write(std::slice::from_ref(b))?, 1);
+    }
+
+    let mut expected: Vec<u8> = vec![];
+    expected.write_string_utf16_le(TEXT)?;
+    assert_eq!(writer.finish()?, expected);
+    return Ok(());
+}
+
+#[test]
+fn test_write_invalid() -> io::Result<()> {
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    assert_eq!(writer.write(&[0x41, 0xFF, 0x42])?, 1);
+    assert_eq!(writer.write(&[0xFF, 0x42]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    writer.write_all(&[0x41, 0xE2, 0x82])?;
+    assert_eq!(writer.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+
+    //The pending char turns out to be invalid after its continuation byte was taken from the second write.
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    assert_eq!(writer.write(&[0x41, 0xE2])?, 2);
+    assert_eq!(writer.write(&[0x41, 0x82, 0x42])?, 2);
+    assert_eq!(writer.write(&[0x42]).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    writer.write_all(&[0xE2])?;
+    assert_eq!(writer.write(&[0x41, 0x82])?, 2);
+    assert_eq!(writer.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Utf16LE);
+    writer.write_all(&[0xE2])?;
+    assert_eq!(writer.write(&[0x41, 0x82])?, 2);
+    assert_eq!(writer.flush().unwrap_err().kind(), ErrorKind::InvalidData);
+    writer.flush()?;
+
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Latin1);
+    assert_eq!(writer.write_all("€".as_bytes()).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    //A char that cannot be encoded keeps the pending bytes and consumes nothing of buf.
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Latin1);
+    assert_eq!(writer.write(&[0xE2])?, 1);
+    assert_eq!(writer.write(&[0x82, 0xAC]).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.finish().unwrap_err().to_string(), "utf-8 data ends in the middle of a char");
+
+    //A completed pending char is written before a later char fails to encode.
+    let mut writer = TranscodeWriter::new(Vec::new(), TranscodeEncoding::Latin1);
+    assert_eq!(writer.write(&[0xC3])?, 1);
+    assert_eq!(writer.write(&[0xA9, 0xE2, 0x82, 0xAC])?, 1);
+    assert_eq!(writer.write("€".as_bytes()).unwrap_err().kind(), ErrorKind::InvalidInput);
+    assert_eq!(writer.finish()?, vec![0xE9]);
+    return Ok(());
+}
+
+#[test]
+fn test_round_trip() -> io::Result<()> {
+    let encodings = [TranscodeEncoding::Utf16LE, TranscodeEncoding::Utf16BE, TranscodeEncoding::Utf32LE,
+        TranscodeEncoding::Utf32BE, TranscodeEncoding::JavaModifiedUtf8];
+    for encoding in encodings.iter() {
+        let data = encode(*encoding, TEXT)?;
+        let mut reader = TranscodeReader::new(Cursor::new(data), *encoding);
+        let mut writer = TranscodeWriter::new(Vec::new(), *encoding);
+        io::copy(&mut reader, &mut writer)?;
+        assert_eq!(writer.finish()?, encode(*encoding, TEXT)?);
+    }
+    return Ok(());
+}
@@ -0,0 +1,73 @@
+use std::io;
+use std::io::{Cursor, ErrorKind};
+use rw_utils::string_read::StringRead;
+use rw_utils::string_write::StringWrite;
+use rw_utils::wtf8::Wtf8Buf;
+
+#[test]
+fn test_lone_surrogate_roundtrip() -> io::Result<()> {
+    //"a", lone high surrogate, "b", lone low surrogate, pile of poo as surrogate pair
+    let units: Vec<u16> = vec![0x61, 0xD800, 0x62, 0xDC00, 0xD83D, 0xDCA9];
+    let mut data: Vec<u8> = vec![];
+    for u in units.iter() {
+        data.extend_from_slice(&u.to_le_bytes());
+    }
+
+    let mut cursor = Cursor::new(&data);
+    assert_eq!(cursor.read_string_utf16_le(units.len()).unwrap_err().kind(), ErrorKind::InvalidData);
+
+    let mut cursor = Cursor::new(&data);
+    let wtf8 = cursor.read_wtf8_utf16_le(units.len())?;
+    assert_eq!(wtf8.as_bytes(), &[0x61, 0xED, 0xA0, 0x80, 0x62, 0xED, 0xB0, 0x80, 0xF0, 0x9F, 0x92, 0xA9]);
+    assert!(wtf8.has_surrogates());
+    assert_eq!(wtf8.to_utf16(), units);
+    assert_eq!(wtf8.to_string_lossy(), "a\u{FFFD}b\u{FFFD}\u{1F4A9}");
+
+    let mut out: Vec<u8> = vec![];
+    assert_eq!(out.write_wtf8_utf16_le(&wtf8)?, data.len());
+    assert_eq!(out, data);
+
+    let mut out: Vec<u8> = vec![];
+    out.write_wtf8_utf16_be(&wtf8)?;
+    let mut cursor = Cursor::new(&out);
+    assert_eq!(cursor.read_wtf8_utf16_be(units.len())?, wtf8);
+
+    let copy = Wtf8Buf::from_bytes(wtf8.clone().into_bytes())?;
+    assert_eq!(copy, wtf8);
+
+    return Ok(());
+}
+
+#[test]
+fn test_valid_utf16() -> io::Result<()> {
+    let mut data: Vec<u8> = vec![];
+    data.write_string_utf16_be("Program Files\\ä\u{1F4A9}")?;
+    let mut cursor = Cursor::new(&data);
+    let wtf8 = cursor.read_wtf8_utf16_be(data.len() / 2)?;
+    assert!(!wtf8.has_surrogates());
+    assert_eq!(wtf8.as_str(), Some("Program Files\\ä\u{1F4A9}"));
+    assert_eq!(wtf8.into_string().unwrap(), "Program Files\\ä\u{1F4A9}");
+
+    return Ok(());
+}
+
+#[test]
+fn test_invalid_wtf8() {
+    //encoded surrogate pair is not allowed in wtf-8, it must be a 4 byte sequence
+    assert!(Wtf8Buf::from_bytes(vec![0xED, 0xA0, 0xBD, 0xED, 0xB2, 0xA9]).is_err());
+    //overlong encoding
+    assert!(Wtf8Buf::from_bytes(vec![0xC0, 0x80]).is_err());
+    //truncated
+    assert!(Wtf8Buf::from_bytes(vec![0xE2, 0x82]).is_err());
+    assert!(Wtf8Buf::from_bytes(vec![0xED, 0xA0, 0xBD]).is_ok());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_os_string() -> io::Result<()> {
+    let wtf8 = Wtf8Buf::from_utf16(&[0x61, 0xDC00]);
+    let os = wtf8.clone().into_os_string();
+    assert_eq!(Wtf8Buf::from_os_string(os)?, wtf8);
+
+    return Ok(());
+}